@@ -0,0 +1,772 @@
+use advent_of_code_2022::common::days::{self, InputKind};
+use advent_of_code_2022::common::differential;
+use advent_of_code_2022::common::fetch::{self, Client, Config, Fetched};
+use advent_of_code_2022::common::generate::{self, DEFAULT_SIZE};
+use advent_of_code_2022::common::output::{self, Format};
+use advent_of_code_2022::common::readme::{self, Example};
+use advent_of_code_2022::common::registry::{self, Check};
+use advent_of_code_2022::common::scaffold::{self, Change, Template};
+use advent_of_code_2022::common::submit::{self, History, Response, Verdict};
+use advent_of_code_2022::common::timing::{self, Budget, Timing, TimingReport};
+use advent_of_code_2022::common::{
+    input_name, read_input, Answer, Args, Part, Registry, Report, Solver,
+};
+use advent_of_code_2022::day15::{Day15, Search};
+use std::env;
+use std::io;
+use std::path::Path;
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "\
+Usage: aoc run <day|all> [--part 1|2] [--input small|full|<file>|-] [--format text|json]
+               [--row <row>] [--bound <bound>]
+       aoc verify [<day|all>] [--input small|full]
+       aoc time [<day|all>] [--part 1|2] [--input small|full] [--threads <number>]
+       aoc gen <day> [--seed <number>] [--size <number>]
+       aoc examples <day|all> [--write [--force]]
+       aoc fetch <day|all>
+       aoc submit <day> <part> [--answer <answer>]
+       aoc new <day> [--template grid|lines|blocks] [--force]
+
+  run       solve and print a table of answers and times, `all` skips
+            days without the input
+  verify    compare against the known answers in answers.toml
+  time      solve the days side by side and print them slowest first,
+            with the total of their times against the budget in
+            budget.toml. Days without the input are skipped
+  gen       print a random input, the same seed and size give the same input
+  examples  print the example and its answers from dayNN/README, and
+            whether the solver agrees
+  fetch     download dayNN/input.txt and dayNN/README, files that are
+            already there aren't downloaded again. Needs the session
+            cookie of the site in AOC_SESSION or as `session` in aoc.toml,
+            AOC_BASE_URL or `base_url` point it at another server
+  submit    solve a part on dayNN/input.txt and send the answer, like
+            fetch. Guesses are kept in submissions.toml and answers that
+            earlier guesses rule out aren't sent. A correct answer is
+            added to answers.toml
+  new       start a day: dayNN/ with a solver for the shape of the input
+            and its entries in Cargo.toml, lib.rs, the tests and the
+            benchmarks. A README that's there already gives the small
+            input and its answers, like examples --write
+
+  <day>     day number 1-25, or `all` for every day. Where `all` is
+            allowed so are lists of days and ranges, like 1-5,8
+  --part    only solve the given part, both parts are solved by default
+  --input   `small` for dayNN/small_input.txt, `full` for dayNN/input.txt,
+            run and time default to full and verify to both. A single
+            day can also be run on any file, or on standard input with `-`
+  --format  `text` for a table, `json` for one object per solved part and
+            line, text by default
+  --row     the row day 15 counts in for part 1, the example's for
+            small and the full input's otherwise
+  --bound   the side of the square day 15 searches for part 2, set like
+            --row
+  --threads  how many days are solved at once, one per core by default
+  --seed    seed of the random input, 0 by default
+  --size    how large the random input is, like the number of lines or the
+            side of a grid depending on the day, 20 by default
+  --answer  send this answer instead of solving, for answers drawn as
+            an image
+  --template  the shape of the input the new solver parses, `grid` of
+            characters, number `lines` or `blocks` of them separated by
+            blank lines, lines by default
+  --write   save the examples as dayNN/small_input.txt and their answers
+            in answers.toml. Small inputs that differ from the example
+            are kept, as are those of days 10 and 13, which aren't the
+            example
+  --force   overwrite small inputs that differ from the example";
+
+/// The solved parts of one day
+struct Solved {
+    day: u32,
+    input: String,
+    report: Report,
+}
+
+/// Invalid command line
+fn fail(message: &str) -> ! {
+    eprintln!("aoc: {}\n\n{}", message, USAGE);
+    process::exit(2);
+}
+
+/// Unreadable or malformed input
+fn error(message: &str) -> ! {
+    eprintln!("error: {}", message);
+    process::exit(1);
+}
+
+fn parse_day(day: &str) -> Result<u32, String> {
+    match day.parse::<u32>() {
+        Ok(day) if days::DAYS.contains(&day) => Ok(day),
+        _ => Err(format!("`{}` is not a day between 1 and 25", day)),
+    }
+}
+
+/// `all`, a day, or days and ranges of them like `1-5,8`
+fn parse_days(selection: &str) -> Result<Vec<u32>, String> {
+    if selection == "all" {
+        return Ok(days::DAYS.collect());
+    }
+    let mut days = Vec::new();
+    for range in selection.split(',') {
+        match range.split_once('-') {
+            Some((first, last)) => {
+                let (first, last) = (parse_day(first)?, parse_day(last)?);
+                if first > last {
+                    return Err(format!(
+                        "`{}` is a range from a later to an earlier day",
+                        range
+                    ));
+                }
+                days.extend(first..=last);
+            }
+            None => days.push(parse_day(range)?),
+        }
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+fn parse_parts(args: &Args) -> Result<Vec<Part>, String> {
+    match args.option("part") {
+        None => Ok(Part::BOTH.to_vec()),
+        Some(part) => part
+            .parse::<u32>()
+            .ok()
+            .and_then(Part::from_number)
+            .map(|part| vec![part])
+            .ok_or_else(|| format!("`{}` is not a part, expected 1 or 2", part)),
+    }
+}
+
+fn parse_input_kind(args: &Args) -> Result<Option<InputKind>, String> {
+    args.option("input")
+        .map(|name| {
+            InputKind::from_name(name)
+                .ok_or_else(|| format!("`{}` is not an input, expected small or full", name))
+        })
+        .transpose()
+}
+
+fn run(args: &Args) -> Result<Vec<Solved>, String> {
+    let selection = args
+        .positional()
+        .get(1)
+        .ok_or_else(|| String::from("missing day"))?;
+    args.check_options(&["part", "input", "format", "row", "bound"])?;
+    let days = parse_days(selection)?;
+    let (row, bound) = (args.option("row"), args.option("bound"));
+    if (row.is_some() || bound.is_some()) && !days.contains(&15) {
+        return Err(String::from("--row and --bound are only for day 15"));
+    }
+    let parts = parse_parts(args)?;
+    let input = args.option("input").unwrap_or("full");
+    let kind = InputKind::from_name(input);
+    if kind.is_none() && days.len() > 1 {
+        return Err(format!("`{}` is a file, which needs a single day", input));
+    }
+
+    let mut solved = Vec::new();
+    for day in days {
+        let path = match kind {
+            Some(kind) => days::input_path(day, kind).display().to_string(),
+            None => input.to_string(),
+        };
+        let contents = match read_input(&path) {
+            // `all` skips days that don't ship the requested input yet
+            Ok(contents) if contents.is_empty() && selection == "all" => continue,
+            Ok(contents) => contents,
+            Err(reason) if reason.kind() == io::ErrorKind::NotFound && selection == "all" => {
+                continue
+            }
+            Err(reason) => error(&format!("{}: {}", input_name(&path), reason)),
+        };
+
+        let puzzle = match kind {
+            _ if day == 15 && (row.is_some() || bound.is_some()) => {
+                let search = match kind {
+                    Some(InputKind::Small) => Search::EXAMPLE,
+                    _ => Search::FULL,
+                };
+                Some(Box::new(Day15 {
+                    search: search.with_options(row, bound)?,
+                }) as Box<dyn Solver>)
+            }
+            Some(kind) => days::puzzle_for(day, kind),
+            None => days::puzzle(day),
+        };
+        let report = puzzle
+            .unwrap()
+            .solve(&contents, &parts)
+            .unwrap_or_else(|reason| error(&reason.with_file(input_name(&path)).to_string()));
+        solved.push(Solved {
+            day,
+            input: input_name(&path).to_string(),
+            report,
+        });
+    }
+    Ok(solved)
+}
+
+fn print_table(solved: &[Solved]) {
+    let answers = solved
+        .iter()
+        .flat_map(|day| &day.report.solutions)
+        .map(|solution| solution.answer.to_string())
+        .collect::<Vec<String>>();
+    let answer_width = answers
+        .iter()
+        .flat_map(|answer| answer.lines())
+        .map(|line| line.len())
+        .max()
+        .unwrap_or(0)
+        .max("Answer".len());
+
+    println!("Day  Part  {:<answer_width$}  Parse        Solve", "Answer");
+    let mut answers = answers.iter();
+    for day in solved {
+        for (index, solution) in day.report.solutions.iter().enumerate() {
+            let mut lines = answers.next().unwrap().lines();
+            // the input is parsed once for all parts of a day
+            let parse_time = if index == 0 {
+                format!("{:?}", day.report.parse_time)
+            } else {
+                String::new()
+            };
+            println!(
+                "{:>3}  {:>4}  {:<answer_width$}  {:<11}  {:?}",
+                day.day,
+                solution.part.number(),
+                lines.next().unwrap_or(""),
+                parse_time,
+                solution.elapsed
+            );
+            // multi line answers, like an image, continue below the first line
+            for line in lines {
+                println!("           {}", line);
+            }
+        }
+    }
+
+    let total: Duration = solved
+        .iter()
+        .map(|day| {
+            day.report.parse_time
+                + day
+                    .report
+                    .solutions
+                    .iter()
+                    .map(|solution| solution.elapsed)
+                    .sum::<Duration>()
+        })
+        .sum();
+    println!("Total{:>width$}{:?}", "", total, width = answer_width + 8);
+}
+
+fn print_json(solved: &[Solved]) {
+    for day in solved {
+        for line in output::json_lines(day.day, &day.input, &day.report) {
+            println!("{}", line);
+        }
+    }
+}
+
+fn time(args: &Args) -> Result<TimingReport, String> {
+    args.check_options(&["part", "input", "threads"])?;
+    let days = parse_days(args.positional().get(1).map_or("all", String::as_str))?;
+    let parts = parse_parts(args)?;
+    let kind = parse_input_kind(args)?.unwrap_or(InputKind::Full);
+    let threads = args
+        .option("threads")
+        .map(|threads| match threads.parse::<usize>() {
+            Ok(threads) if threads > 0 => Ok(threads),
+            _ => Err(format!("`{}` is not a number of threads", threads)),
+        })
+        .transpose()?;
+    Ok(timing::time_days(&days, kind, &parts, threads).unwrap_or_else(|reason| error(&reason)))
+}
+
+/// Prints the report and the total against the budget, returns whether all
+/// days were solved within it
+fn print_timings(report: &TimingReport, budget: Option<Budget>) -> bool {
+    let elapsed = |part: Part, timing: &Timing| {
+        let report = timing.outcome.as_ref().ok()?;
+        let solution = report
+            .solutions
+            .iter()
+            .find(|solution| solution.part == part)?;
+        Some(format!("{:?}", solution.elapsed))
+    };
+
+    println!("Day  Time          Parse         Part 1        Part 2        Answers");
+    for timing in &report.timings {
+        let report = match &timing.outcome {
+            Ok(report) => report,
+            Err(reason) => {
+                println!("{:>3}  failed: {}", timing.day, reason);
+                continue;
+            }
+        };
+        let answers = report
+            .solutions
+            .iter()
+            .map(|solution| match &solution.answer {
+                // only the table is kept to one line per day
+                Answer::Image(_) => String::from("(image)"),
+                answer => answer.to_string(),
+            })
+            .collect::<Vec<String>>();
+        println!(
+            "{:>3}  {:<12}  {:<12}  {:<12}  {:<12}  {}",
+            timing.day,
+            format!("{:?}", timing.elapsed()),
+            format!("{:?}", report.parse_time),
+            elapsed(Part::One, timing).unwrap_or_default(),
+            elapsed(Part::Two, timing).unwrap_or_default(),
+            answers.join("  ")
+        );
+    }
+    if !report.skipped.is_empty() {
+        let skipped = report
+            .skipped
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<String>>();
+        println!("Skipped days without input: {}", skipped.join(", "));
+    }
+
+    let total = report.total();
+    println!(
+        "Total {:?}, {:?} from start to end on {} thread{}",
+        total,
+        report.wall_time,
+        report.threads,
+        if report.threads == 1 { "" } else { "s" }
+    );
+    let failures = report.failures().count();
+    if failures > 0 {
+        println!("{} failed", failures);
+    }
+    let within_budget = match budget {
+        Some(budget) if total <= budget.total() => {
+            println!(
+                "Within the budget of {:?} by {:?}",
+                budget.total(),
+                budget.total() - total
+            );
+            true
+        }
+        Some(budget) => {
+            println!(
+                "Over the budget of {:?} by {:?}",
+                budget.total(),
+                total - budget.total()
+            );
+            false
+        }
+        None => true,
+    };
+    failures == 0 && within_budget
+}
+
+fn verify(args: &Args) -> Result<Vec<Check>, String> {
+    args.check_options(&["input"])?;
+    let days = parse_days(args.positional().get(1).map_or("all", String::as_str))?;
+    let kinds = match parse_input_kind(args)? {
+        Some(kind) => vec![kind],
+        None => InputKind::ALL.to_vec(),
+    };
+    let registry =
+        Registry::load(&registry::registry_path()).unwrap_or_else(|reason| error(&reason));
+
+    let mut checks = Vec::new();
+    for day in days {
+        for kind in &kinds {
+            checks.extend(
+                registry
+                    .check(day, *kind)
+                    .unwrap_or_else(|reason| error(&reason)),
+            );
+        }
+    }
+    Ok(checks)
+}
+
+fn gen(args: &Args) -> Result<String, String> {
+    args.check_options(&["seed", "size"])?;
+    let day = match args.positional().get(1) {
+        Some(day) if day == "all" => return Err(String::from("gen needs a single day")),
+        Some(day) => parse_day(day)?,
+        None => return Err(String::from("missing day")),
+    };
+    let generator =
+        days::generator(day).ok_or_else(|| format!("day {} can't generate inputs yet", day))?;
+
+    let seed = match args.option("seed") {
+        Some(seed) => seed
+            .parse::<u64>()
+            .map_err(|_| format!("`{}` is not a seed, expected a number", seed))?,
+        None => 0,
+    };
+    let size = match args.option("size") {
+        Some(size) => size
+            .parse::<usize>()
+            .ok()
+            .filter(|size| *size > 0)
+            .ok_or_else(|| format!("`{}` is not a size, expected a positive number", size))?,
+        None => DEFAULT_SIZE,
+    };
+    Ok(generate::generate(generator.as_ref(), seed, size))
+}
+
+/// Saves `example` as the small input of `day`, and its answers. Days with
+/// a small input of their own keep it, and so does a day whose small input
+/// differs from the example unless `force` is set, which is returned as the
+/// reason nothing was saved.
+fn write_example(
+    day: u32,
+    example: &Example,
+    registry: &mut Registry,
+    force: bool,
+) -> Result<(), &'static str> {
+    let small = days::input_path(day, InputKind::Small);
+    if readme::OWN_SMALL_INPUTS.contains(&day) {
+        return Err("kept, the day has a small input of its own");
+    }
+    match std::fs::read_to_string(&small) {
+        Ok(contents) if contents != example.input && !force => {
+            return Err("kept, it differs from the example, --force overwrites it")
+        }
+        Ok(_) => {}
+        Err(reason) if reason.kind() == io::ErrorKind::NotFound => {}
+        Err(reason) => error(&format!("{}: {}", small.display(), reason)),
+    }
+
+    std::fs::write(&small, &example.input)
+        .unwrap_or_else(|reason| error(&format!("{}: {}", small.display(), reason)));
+    for part in Part::BOTH {
+        if let Some(answer) = example.answers.get(part) {
+            registry.insert(day, InputKind::Small, part, answer.to_string());
+        }
+    }
+    Ok(())
+}
+
+fn examples(args: &Args) -> Result<(), String> {
+    args.check_options(&["write", "force"])?;
+    if args.flag("force") && !args.flag("write") {
+        return Err(String::from("--force needs --write"));
+    }
+    let selection = args
+        .positional()
+        .get(1)
+        .ok_or_else(|| String::from("missing day"))?;
+    let days = parse_days(selection)?;
+    let registry_path = registry::registry_path();
+    let mut registry = Registry::load(&registry_path).unwrap_or_else(|reason| error(&reason));
+
+    for day in days {
+        let path = readme::readme_path(day);
+        // `all` skips days whose description isn't there yet
+        let description = match std::fs::read_to_string(&path) {
+            Ok(contents) if contents.trim().is_empty() && selection == "all" => continue,
+            Ok(contents) => contents,
+            Err(reason) if reason.kind() == io::ErrorKind::NotFound && selection == "all" => {
+                continue
+            }
+            Err(reason) => error(&format!("{}: {}", path.display(), reason)),
+        };
+        let example = match readme::extract(&description) {
+            Ok(example) => example,
+            Err(_) if selection == "all" => continue,
+            Err(reason) => error(&format!("{}: {}", path.display(), reason)),
+        };
+
+        println!("Day {} example:", day);
+        print!("{}", example.input);
+        let solved = differential::solve(
+            days::puzzle_for(day, InputKind::Small).unwrap().as_ref(),
+            &example.input,
+        );
+        for (index, part) in Part::BOTH.into_iter().enumerate() {
+            let Some(answer) = example.answers.get(part) else {
+                println!("  part {}: no answer found", part);
+                continue;
+            };
+            let agrees = match &solved {
+                Ok(answers) if answers[index] == answer => String::from("ok"),
+                Ok(answers) => format!("the solver gives {}", answers[index]),
+                Err(reason) => format!("the solver fails, {}", reason.lines().next().unwrap()),
+            };
+            println!(
+                "  part {}: {}  {}",
+                part,
+                answer.replace('\n', "\n          "),
+                agrees
+            );
+        }
+
+        if args.flag("write") {
+            if let Err(reason) = write_example(day, &example, &mut registry, args.flag("force")) {
+                println!(
+                    "  {}  {}",
+                    days::input_path(day, InputKind::Small).display(),
+                    reason
+                );
+            }
+        }
+    }
+
+    if args.flag("write") {
+        registry
+            .save(&registry_path)
+            .unwrap_or_else(|reason| error(&reason));
+    }
+    Ok(())
+}
+
+fn fetch(args: &Args) -> Result<(), String> {
+    args.check_options(&[])?;
+    let selection = args
+        .positional()
+        .get(1)
+        .ok_or_else(|| String::from("missing day"))?;
+    let days = parse_days(selection)?;
+    let config = Config::load(&fetch::config_path()).unwrap_or_else(|reason| error(&reason));
+    let client = Client::new(&config).unwrap_or_else(|reason| error(&reason));
+
+    for day in days {
+        let fetched = fetch::fetch(&client, day, &days::day_directory(day))
+            .unwrap_or_else(|reason| error(&reason));
+        for (path, how) in fetched {
+            let how = match how {
+                Fetched::Downloaded => "downloaded",
+                Fetched::Cached => "cached",
+            };
+            println!("{}  {}", path.display(), how);
+        }
+    }
+    Ok(())
+}
+
+fn submit(args: &Args) -> Result<(), String> {
+    args.check_options(&["answer"])?;
+    let day = match args.positional().get(1) {
+        Some(day) if day == "all" => return Err(String::from("submit needs a single day")),
+        Some(day) => parse_day(day)?,
+        None => return Err(String::from("missing day")),
+    };
+    let part = match args.positional().get(2) {
+        Some(part) => part
+            .parse::<u32>()
+            .ok()
+            .and_then(Part::from_number)
+            .ok_or_else(|| format!("`{}` is not a part, expected 1 or 2", part))?,
+        None => return Err(String::from("missing part")),
+    };
+
+    let answer = match args.option("answer") {
+        Some(answer) => answer.to_string(),
+        None => {
+            let path = days::input_path(day, InputKind::Full);
+            let contents = std::fs::read_to_string(&path)
+                .unwrap_or_else(|reason| error(&format!("{}: {}", path.display(), reason)));
+            let report = days::puzzle(day)
+                .unwrap()
+                .solve(&contents, &[part])
+                .unwrap_or_else(|reason| {
+                    error(&reason.with_file(path.display().to_string()).to_string())
+                });
+            match &report.solutions[0].answer {
+                Answer::Image(image) => error(&format!(
+                    "the answer is an image, send the letters with --answer\n{}",
+                    image
+                )),
+                answer => answer.to_string(),
+            }
+        }
+    };
+
+    let history_path = submit::history_path();
+    let mut history = History::load(&history_path).unwrap_or_else(|reason| error(&reason));
+    if let Some(reason) = history.refuse(day, part, &answer) {
+        error(&format!("not sending {}: {}", answer, reason));
+    }
+
+    let config = Config::load(&fetch::config_path()).unwrap_or_else(|reason| error(&reason));
+    let client = Client::new(&config).unwrap_or_else(|reason| error(&reason));
+    let page = client
+        .answer(day, part, &answer)
+        .unwrap_or_else(|reason| error(&reason));
+    match Response::parse(&page).unwrap_or_else(|reason| error(&reason)) {
+        Response::Judged(verdict) => {
+            history.record(day, part, &answer, verdict);
+            history
+                .save(&history_path)
+                .unwrap_or_else(|reason| error(&reason));
+            println!("day {} part {}: {} is {}", day, part, answer, verdict);
+
+            if verdict == Verdict::Correct {
+                let registry_path = registry::registry_path();
+                let mut registry =
+                    Registry::load(&registry_path).unwrap_or_else(|reason| error(&reason));
+                registry.insert(day, InputKind::Full, part, answer);
+                registry
+                    .save(&registry_path)
+                    .unwrap_or_else(|reason| error(&reason));
+            } else {
+                process::exit(1);
+            }
+        }
+        Response::RateLimited(wait) => error(&format!(
+            "answered too recently, try again in {}s",
+            wait.as_secs()
+        )),
+        Response::WrongLevel => error(&format!(
+            "day {} part {} can't be answered, it's solved already or part 1 isn't",
+            day, part
+        )),
+    }
+    Ok(())
+}
+
+fn new(args: &Args) -> Result<(), String> {
+    args.check_options(&["template", "force"])?;
+    let day = match args.positional().get(1) {
+        Some(day) if day == "all" => return Err(String::from("new needs a single day")),
+        Some(day) => parse_day(day)?,
+        None => return Err(String::from("missing day")),
+    };
+    let template = match args.option("template") {
+        Some(name) => Template::from_name(name).ok_or_else(|| {
+            format!(
+                "`{}` is not a template, expected grid, lines or blocks",
+                name
+            )
+        })?,
+        None => Template::Lines,
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let changes = scaffold::scaffold(root, day, template).unwrap_or_else(|reason| error(&reason));
+    for (path, change) in changes {
+        let change = match change {
+            Change::Created => "created",
+            Change::Updated => "updated",
+            Change::Unchanged => "unchanged",
+        };
+        let path = path.strip_prefix(root).unwrap_or(&path);
+        println!("{}  {}", path.display(), change);
+    }
+
+    let description = std::fs::read_to_string(readme::readme_path(day)).unwrap_or_default();
+    if let Ok(example) = readme::extract(&description) {
+        let registry_path = registry::registry_path();
+        let mut registry = Registry::load(&registry_path).unwrap_or_else(|reason| error(&reason));
+        let written = write_example(day, &example, &mut registry, args.flag("force"));
+        registry
+            .save(&registry_path)
+            .unwrap_or_else(|reason| error(&reason));
+        println!(
+            "{}  {}",
+            days::input_path(day, InputKind::Small)
+                .strip_prefix(root)
+                .unwrap()
+                .display(),
+            written.err().unwrap_or("example from the README")
+        );
+    }
+    Ok(())
+}
+
+fn print_checks(checks: &[Check]) -> bool {
+    println!("Day  Part  Input  Result  Time");
+    for check in checks {
+        println!(
+            "{:>3}  {:>4}  {:<5}  {:<6}  {:?}",
+            check.day,
+            check.part.number(),
+            check.kind.name(),
+            if check.passed() { "ok" } else { "FAILED" },
+            check.elapsed
+        );
+        if !check.passed() {
+            println!(
+                "       expected: {}",
+                check.expected.replace('\n', "\n                 ")
+            );
+            println!(
+                "       actual:   {}",
+                check.actual.replace('\n', "\n                 ")
+            );
+        }
+    }
+
+    let failed = checks.iter().filter(|check| !check.passed()).count();
+    println!("{} checked, {} failed", checks.len(), failed);
+    failed == 0
+}
+
+fn main() {
+    let args = Args::parse_with_flags(env::args().skip(1), &["write", "force"])
+        .unwrap_or_else(|error| fail(&error));
+
+    match args.positional().first().map(String::as_str) {
+        Some("run") => {
+            let format =
+                Format::from_option(args.option("format")).unwrap_or_else(|error| fail(&error));
+            match run(&args) {
+                Ok(solved) if format == Format::Json => print_json(&solved),
+                Ok(solved) => print_table(&solved),
+                Err(error) => fail(&error),
+            }
+        }
+        Some("time") => match time(&args) {
+            Ok(report) => {
+                let budget =
+                    Budget::load(&timing::budget_path()).unwrap_or_else(|reason| error(&reason));
+                if !print_timings(&report, budget) {
+                    process::exit(1);
+                }
+            }
+            Err(error) => fail(&error),
+        },
+        Some("verify") => match verify(&args) {
+            Ok(checks) => {
+                if !print_checks(&checks) {
+                    process::exit(1);
+                }
+            }
+            Err(error) => fail(&error),
+        },
+        Some("gen") => match gen(&args) {
+            Ok(input) => print!("{}", input),
+            Err(error) => fail(&error),
+        },
+        Some("examples") => {
+            if let Err(error) = examples(&args) {
+                fail(&error);
+            }
+        }
+        Some("fetch") => {
+            if let Err(error) = fetch(&args) {
+                fail(&error);
+            }
+        }
+        Some("submit") => {
+            if let Err(error) = submit(&args) {
+                fail(&error);
+            }
+        }
+        Some("new") => {
+            if let Err(error) = new(&args) {
+                fail(&error);
+            }
+        }
+        Some(command) => fail(&format!("unknown command `{}`", command)),
+        None => fail("missing command"),
+    }
+}
@@ -0,0 +1,113 @@
+use advent_of_code_2022::common::days::{self, InputKind};
+use advent_of_code_2022::*;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Benchmark parsing and both parts of a day, each against the given inputs
+fn bench_day<P: Puzzle>(c: &mut Criterion, day: u32, puzzle: &P, kinds: &[InputKind]) {
+    for kind in kinds {
+        let contents = match std::fs::read_to_string(days::input_path(day, *kind)) {
+            // an empty input is a day that isn't started yet
+            Ok(contents) if !contents.is_empty() => contents,
+            _ => continue,
+        };
+
+        let mut group = c.benchmark_group(format!("day{:02}/{}", day, kind.name()));
+        if *kind == InputKind::Full {
+            // some full inputs take most of a second per iteration
+            group.sample_size(10);
+        }
+
+        group.bench_function("parse", |b| b.iter(|| puzzle.parse(black_box(&contents))));
+
+        let input = puzzle.parse(&contents).unwrap();
+        group.bench_function("part1", |b| b.iter(|| puzzle.part1(black_box(&input))));
+        group.bench_function("part2", |b| b.iter(|| puzzle.part2(black_box(&input))));
+        group.finish();
+    }
+}
+
+fn day01(c: &mut Criterion) {
+    bench_day(c, 1, &day01::Day01, &InputKind::ALL);
+}
+
+fn day02(c: &mut Criterion) {
+    bench_day(c, 2, &day02::Day02, &InputKind::ALL);
+}
+
+fn day03(c: &mut Criterion) {
+    bench_day(c, 3, &day03::Day03, &InputKind::ALL);
+}
+
+fn day04(c: &mut Criterion) {
+    bench_day(c, 4, &day04::Day04, &InputKind::ALL);
+}
+
+fn day05(c: &mut Criterion) {
+    bench_day(c, 5, &day05::Day05, &InputKind::ALL);
+}
+
+fn day06(c: &mut Criterion) {
+    bench_day(c, 6, &day06::Day06, &InputKind::ALL);
+}
+
+fn day07(c: &mut Criterion) {
+    bench_day(c, 7, &day07::Day07, &InputKind::ALL);
+}
+
+fn day08(c: &mut Criterion) {
+    bench_day(c, 8, &day08::Day08, &InputKind::ALL);
+}
+
+fn day09(c: &mut Criterion) {
+    bench_day(c, 9, &day09::Day09, &InputKind::ALL);
+}
+
+fn day10(c: &mut Criterion) {
+    bench_day(c, 10, &day10::Day10, &InputKind::ALL);
+}
+
+fn day11(c: &mut Criterion) {
+    bench_day(c, 11, &day11::Day11, &InputKind::ALL);
+}
+
+fn day12(c: &mut Criterion) {
+    bench_day(c, 12, &day12::Day12, &InputKind::ALL);
+}
+
+fn day13(c: &mut Criterion) {
+    bench_day(c, 13, &day13::Day13, &InputKind::ALL);
+}
+
+fn day14(c: &mut Criterion) {
+    bench_day(c, 14, &day14::Day14, &InputKind::ALL);
+}
+
+fn day15(c: &mut Criterion) {
+    let example = day15::Day15 {
+        search: day15::Search::EXAMPLE,
+    };
+    bench_day(c, 15, &example, &[InputKind::Small]);
+    bench_day(c, 15, &day15::Day15::default(), &[InputKind::Full]);
+}
+
+fn day16(c: &mut Criterion) {
+    bench_day(c, 16, &day16::Day16, &InputKind::ALL);
+}
+
+fn day17(c: &mut Criterion) {
+    bench_day(c, 17, &day17::Day17, &InputKind::ALL);
+}
+
+fn day18(c: &mut Criterion) {
+    bench_day(c, 18, &day18::Day18, &InputKind::ALL);
+}
+
+fn day19(c: &mut Criterion) {
+    bench_day(c, 19, &day19::Day19, &InputKind::ALL);
+}
+
+criterion_group!(
+    benches, day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12,
+    day13, day14, day15, day16, day17, day18, day19
+);
+criterion_main!(benches);
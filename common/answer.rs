@@ -0,0 +1,59 @@
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+/// The answer to one part of a puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// Letters drawn on a screen, one line per row of pixels
+    Image(String),
+}
+
+impl Answer {
+    /// Name of the variant in JSON output
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Number(_) => "number",
+            Answer::Text(_) => "string",
+            Answer::Image(_) => "image",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) | Answer::Image(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+/// Numbers are written as JSON numbers, text and images as strings
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(number) => serializer.serialize_i64(*number),
+            Answer::Text(text) | Answer::Image(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(number: i64) -> Self {
+        Answer::Number(number)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Self {
+        i64::try_from(number).map_or_else(|_| Answer::Text(number.to_string()), Answer::Number)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+/// Command line split into positional arguments, `--name value` options and
+/// `--name` flags
+#[derive(Debug, Default)]
+pub struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+    flags: Vec<String>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        Args::parse_with_flags(args, &[])
+    }
+
+    /// Like `parse`, but the names in `flags` take no value
+    pub fn parse_with_flags(
+        args: impl IntoIterator<Item = String>,
+        flags: &[&str],
+    ) -> Result<Self, String> {
+        let mut parsed = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if let Some(name) = arg.strip_prefix("--").filter(|name| flags.contains(name)) {
+                parsed.flags.push(name.to_string());
+            } else if let Some(name) = arg.strip_prefix("--") {
+                let (name, value) = match name.split_once('=') {
+                    Some((name, value)) => (name.to_string(), value.to_string()),
+                    None => (
+                        name.to_string(),
+                        args.next()
+                            .ok_or_else(|| format!("missing value for --{}", name))?,
+                    ),
+                };
+                parsed.options.insert(name, value);
+            } else {
+                parsed.positional.push(arg);
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(String::as_str)
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+
+    /// Reject options and flags that aren't in `known`
+    pub fn check_options(&self, known: &[&str]) -> Result<(), String> {
+        match self
+            .options
+            .keys()
+            .chain(&self.flags)
+            .find(|name| !known.contains(&name.as_str()))
+        {
+            Some(name) => Err(format!("unknown option --{}", name)),
+            None => Ok(()),
+        }
+    }
+}
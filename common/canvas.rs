@@ -0,0 +1,170 @@
+use crate::common::point::{BoundingBox, Point};
+use image::{Rgb, RgbImage};
+
+pub type Color = [u8; 3];
+
+pub const WHITE: Color = [255, 255, 255];
+
+/// Colors picked by index, indices past the end get the last color
+#[derive(Debug, Clone)]
+pub struct Palette {
+    colors: Vec<Color>,
+}
+
+impl Palette {
+    pub fn new(colors: &[Color]) -> Self {
+        assert!(!colors.is_empty(), "a palette needs at least one color");
+        Palette {
+            colors: colors.to_vec(),
+        }
+    }
+
+    /// `count` colors evenly spread from `from` to `to`
+    pub fn gradient(from: Color, to: Color, count: usize) -> Self {
+        let steps = count.max(2) as i32 - 1;
+        let colors = (0..count.max(1) as i32)
+            .map(|step| {
+                let mut color = from;
+                for channel in 0..3 {
+                    let range = to[channel] as i32 - from[channel] as i32;
+                    color[channel] = (from[channel] as i32 + range * step / steps) as u8;
+                }
+                color
+            })
+            .collect();
+        Palette { colors }
+    }
+
+    pub fn color(&self, index: usize) -> Color {
+        self.colors[index.min(self.colors.len() - 1)]
+    }
+}
+
+/// Collects colored cells in world coordinates and turns them into an image.
+/// Every cell becomes a `scale` × `scale` square, surrounded by a `border` of
+/// background cells. Unless fixed bounds are given the image grows to fit
+/// everything that was drawn.
+#[derive(Debug, Clone)]
+pub struct Canvas {
+    scale: u32,
+    border: u32,
+    min_size: (u32, u32),
+    background: Color,
+    bounds: Option<BoundingBox>,
+    fixed: bool,
+    cells: Vec<(Point, Color)>,
+}
+
+impl Canvas {
+    pub fn new(scale: u32) -> Self {
+        Canvas {
+            scale: scale.max(1),
+            border: 0,
+            min_size: (1, 1),
+            background: WHITE,
+            bounds: None,
+            fixed: false,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Background cells around the world
+    pub fn border(mut self, border: u32) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Smallest number of cells shown, the world is extended right and down
+    pub fn min_size(mut self, width: u32, height: u32) -> Self {
+        self.min_size = (width.max(1), height.max(1));
+        self
+    }
+
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = color;
+        self
+    }
+
+    pub fn background_color(&self) -> Color {
+        self.background
+    }
+
+    /// Show exactly `bounds`, cells outside are clipped
+    pub fn bounds(mut self, bounds: BoundingBox) -> Self {
+        self.bounds = Some(bounds);
+        self.fixed = true;
+        self
+    }
+
+    /// Grow automatic bounds to `position` without drawing it
+    pub fn include(&mut self, position: Point) {
+        if !self.fixed {
+            self.bounds = Some(match self.bounds {
+                Some(bounds) => bounds.including(position),
+                None => BoundingBox::new(position, position),
+            });
+        }
+    }
+
+    /// Color one cell, later cells are drawn on top of earlier ones
+    pub fn fill(&mut self, position: Point, color: Color) {
+        self.include(position);
+        self.cells.push((position, color));
+    }
+
+    /// Color `width` × `height` cells starting at `corner`
+    pub fn fill_rect(&mut self, corner: Point, width: u32, height: u32, color: Color) {
+        for y in 0..height as i32 {
+            for x in 0..width as i32 {
+                self.fill(corner + Point::new(x, y), color);
+            }
+        }
+    }
+
+    /// Draw rows of palette indices with their top left at `corner`
+    pub fn blit<R: AsRef<[usize]>>(&mut self, corner: Point, pattern: &[R], palette: &Palette) {
+        for (y, row) in pattern.iter().enumerate() {
+            for (x, index) in row.as_ref().iter().enumerate() {
+                self.fill(
+                    corner + Point::new(x as i32, y as i32),
+                    palette.color(*index),
+                );
+            }
+        }
+    }
+
+    pub fn render(&self) -> RgbImage {
+        self.render_scaled(self.scale)
+    }
+
+    /// Render with `scale` pixels per cell instead of the canvas scale
+    pub fn render_scaled(&self, scale: u32) -> RgbImage {
+        let scale = scale.max(1);
+        let bounds = self
+            .bounds
+            .unwrap_or(BoundingBox::new(Point::ORIGIN, Point::ORIGIN));
+        let width = bounds.width().max(self.min_size.0);
+        let height = bounds.height().max(self.min_size.1);
+        let mut image = RgbImage::from_pixel(
+            scale * (width + 2 * self.border),
+            scale * (height + 2 * self.border),
+            Rgb(self.background),
+        );
+
+        for (position, color) in &self.cells {
+            let Point { x: column, y: row } = *position - bounds.min;
+            if column < 0 || row < 0 || column >= width as i32 || row >= height as i32 {
+                continue;
+            }
+
+            let left = scale * (self.border + column as u32);
+            let top = scale * (self.border + row as u32);
+            for offset_y in 0..scale {
+                for offset_x in 0..scale {
+                    image.put_pixel(left + offset_x, top + offset_y, Rgb(*color));
+                }
+            }
+        }
+        image
+    }
+}
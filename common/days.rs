@@ -0,0 +1,110 @@
+use crate::common::{Generate, Solver};
+use crate::*;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+pub const DAYS: RangeInclusive<u32> = 1..=25;
+
+pub fn puzzle(day: u32) -> Option<Box<dyn Solver>> {
+    Some(match day {
+        1 => Box::new(day01::Day01),
+        2 => Box::new(day02::Day02),
+        3 => Box::new(day03::Day03),
+        4 => Box::new(day04::Day04),
+        5 => Box::new(day05::Day05),
+        6 => Box::new(day06::Day06),
+        7 => Box::new(day07::Day07),
+        8 => Box::new(day08::Day08),
+        9 => Box::new(day09::Day09),
+        10 => Box::new(day10::Day10),
+        11 => Box::new(day11::Day11),
+        12 => Box::new(day12::Day12),
+        13 => Box::new(day13::Day13),
+        14 => Box::new(day14::Day14),
+        15 => Box::new(day15::Day15::default()),
+        16 => Box::new(day16::Day16),
+        17 => Box::new(day17::Day17),
+        18 => Box::new(day18::Day18),
+        19 => Box::new(day19::Day19),
+        20 => Box::new(day20::Day20),
+        21 => Box::new(day21::Day21),
+        22 => Box::new(day22::Day22),
+        23 => Box::new(day23::Day23),
+        24 => Box::new(day24::Day24),
+        25 => Box::new(day25::Day25),
+        _ => return None,
+    })
+}
+
+/// The puzzle of `day` set up for its `kind` of input, the day 15 example
+/// searches a smaller row and square than the full input
+pub fn puzzle_for(day: u32, kind: InputKind) -> Option<Box<dyn Solver>> {
+    match (day, kind) {
+        (15, InputKind::Small) => Some(Box::new(day15::Day15 {
+            search: day15::Search::EXAMPLE,
+        })),
+        _ => puzzle(day),
+    }
+}
+
+/// Days that can generate random inputs
+pub fn generator(day: u32) -> Option<Box<dyn Generate>> {
+    Some(match day {
+        1 => Box::new(day01::Day01),
+        2 => Box::new(day02::Day02),
+        3 => Box::new(day03::Day03),
+        4 => Box::new(day04::Day04),
+        5 => Box::new(day05::Day05),
+        6 => Box::new(day06::Day06),
+        7 => Box::new(day07::Day07),
+        8 => Box::new(day08::Day08),
+        9 => Box::new(day09::Day09),
+        10 => Box::new(day10::Day10),
+        11 => Box::new(day11::Day11),
+        12 => Box::new(day12::Day12),
+        13 => Box::new(day13::Day13),
+        14 => Box::new(day14::Day14),
+        _ => return None,
+    })
+}
+
+/// The input files shipped in every day directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Small,
+    Full,
+}
+
+impl InputKind {
+    pub const ALL: [InputKind; 2] = [InputKind::Small, InputKind::Full];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "small" => Some(InputKind::Small),
+            "full" | "input" => Some(InputKind::Full),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputKind::Small => "small",
+            InputKind::Full => "full",
+        }
+    }
+
+    fn file_name(&self) -> &'static str {
+        match self {
+            InputKind::Small => "small_input.txt",
+            InputKind::Full => "input.txt",
+        }
+    }
+}
+
+pub fn day_directory(day: u32) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("day{:02}", day))
+}
+
+pub fn input_path(day: u32, kind: InputKind) -> PathBuf {
+    day_directory(day).join(kind.file_name())
+}
@@ -0,0 +1,208 @@
+use crate::common::days;
+use crate::common::generate::{self, Generate};
+use crate::common::{Part, Solver};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// What a solver returned for an input: the answers of both parts, or the
+/// parse error or panic message
+pub type Outcome = Result<Vec<String>, String>;
+
+/// An input the solver and the reference don't agree on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub day: u32,
+    pub seed: u64,
+    pub size: usize,
+    /// The smallest input found that still disagrees
+    pub input: String,
+    pub expected: Vec<String>,
+    pub actual: Outcome,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "day {} disagrees with the reference, generated with seed {} and size {}, minimized to:",
+            self.day, self.seed, self.size
+        )?;
+        writeln!(f, "{}", self.input.trim_end())?;
+        writeln!(f, "expected: {:?}", self.expected)?;
+        match &self.actual {
+            Ok(answers) => write!(f, "actual:   {:?}", answers),
+            Err(reason) => write!(f, "actual:   {}", reason),
+        }
+    }
+}
+
+/// Smaller variants of an input to try while minimizing
+pub type Shrink = fn(&str) -> Vec<String>;
+
+/// The input without one of its lines, or a run of them
+pub fn shrink_lines(input: &str) -> Vec<String> {
+    let lines = input.lines().collect::<Vec<&str>>();
+    let mut candidates = Vec::new();
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        for start in (0..lines.len()).step_by(chunk) {
+            let kept = lines[..start]
+                .iter()
+                .chain(lines.iter().skip(start + chunk))
+                .map(|line| format!("{}\n", line));
+            candidates.push(kept.collect());
+        }
+        chunk /= 2;
+    }
+    candidates
+}
+
+/// A rectangle of characters without one of its rows or columns
+pub fn shrink_grid(input: &str) -> Vec<String> {
+    let rows = input.lines().collect::<Vec<&str>>();
+    let width = rows.first().map_or(0, |row| row.chars().count());
+    let mut candidates = shrink_lines(input);
+    for column in 0..width {
+        candidates.push(
+            rows.iter()
+                .map(|row| {
+                    row.chars()
+                        .enumerate()
+                        .filter(|(x, _)| *x != column)
+                        .map(|(_, cell)| cell)
+                        .chain(Some('\n'))
+                        .collect::<String>()
+                })
+                .collect(),
+        );
+    }
+    candidates
+}
+
+/// A single line without one of its characters, or a run of them
+pub fn shrink_characters(input: &str) -> Vec<String> {
+    let characters = input.trim_end().chars().collect::<Vec<char>>();
+    let mut candidates = Vec::new();
+    let mut chunk = characters.len() / 2;
+    while chunk > 0 {
+        for start in (0..characters.len()).step_by(chunk) {
+            let kept = characters[..start]
+                .iter()
+                .chain(characters.iter().skip(start + chunk))
+                .chain(Some(&'\n'));
+            candidates.push(kept.collect());
+        }
+        chunk /= 2;
+    }
+    candidates
+}
+
+/// Both parts of `solver`, panics included
+pub fn solve(solver: &dyn Solver, input: &str) -> Outcome {
+    let report = panic::catch_unwind(AssertUnwindSafe(|| solver.solve(input, &Part::BOTH)))
+        .map_err(|payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            format!("panicked: {}", message)
+        })?
+        .map_err(|error| error.to_string())?;
+    Ok(report
+        .solutions
+        .iter()
+        .map(|solution| solution.answer.to_string())
+        .collect())
+}
+
+/// Runs a day's solver against a brute force `reference` on generated inputs
+pub struct Differential {
+    day: u32,
+    generator: Box<dyn Generate>,
+    solver: Box<dyn Solver>,
+    /// Both answers, or `None` for inputs the puzzle has no answer for
+    reference: fn(&str) -> Option<Vec<String>>,
+    shrink: Shrink,
+    cases: u64,
+    sizes: (usize, usize),
+}
+
+impl Differential {
+    /// Panics for days without a generator
+    pub fn new(day: u32, reference: fn(&str) -> Option<Vec<String>>) -> Self {
+        Differential {
+            day,
+            generator: days::generator(day).expect("a day that can generate inputs"),
+            solver: days::puzzle(day).unwrap(),
+            reference,
+            shrink: shrink_lines,
+            cases: 5000,
+            sizes: (1, 10),
+        }
+    }
+
+    /// How to minimize a disagreeing input, lines are dropped by default
+    pub fn shrink(mut self, shrink: Shrink) -> Self {
+        self.shrink = shrink;
+        self
+    }
+
+    /// Number of generated inputs, seeds 0 up to `cases`
+    pub fn cases(mut self, cases: u64) -> Self {
+        self.cases = cases;
+        self
+    }
+
+    /// Sizes passed to the generator, both included, picked by seed
+    pub fn sizes(mut self, smallest: usize, largest: usize) -> Self {
+        self.sizes = (smallest, largest.max(smallest));
+        self
+    }
+
+    /// The reference answers and the solver outcome, if they differ
+    fn compare(&self, input: &str) -> Option<(Vec<String>, Outcome)> {
+        let expected = (self.reference)(input)?;
+        let actual = solve(self.solver.as_ref(), input);
+        if actual.as_ref() == Ok(&expected) {
+            None
+        } else {
+            Some((expected, actual))
+        }
+    }
+
+    /// The first disagreement, minimized
+    pub fn run(&self) -> Result<(), Disagreement> {
+        let (smallest, largest) = self.sizes;
+        for seed in 0..self.cases {
+            let size = smallest + seed as usize % (largest - smallest + 1);
+            let mut input = generate::generate(self.generator.as_ref(), seed, size);
+            let Some(mut difference) = self.compare(&input) else {
+                continue;
+            };
+
+            // keep the first smaller input that still disagrees until none does
+            'shrinking: loop {
+                for candidate in (self.shrink)(&input) {
+                    if let Some(smaller) = self.compare(&candidate) {
+                        input = candidate;
+                        difference = smaller;
+                        continue 'shrinking;
+                    }
+                }
+                break;
+            }
+
+            let (expected, actual) = difference;
+            return Err(Disagreement {
+                day: self.day,
+                seed,
+                size,
+                input,
+                expected,
+                actual,
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Malformed puzzle input, pointing at the offending text and the form that
+/// was expected instead. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+    source_line: String,
+}
+
+/// Byte offset of `inner` in `outer` when `inner` is a slice of it
+fn offset_in(outer: &str, inner: &str) -> Option<usize> {
+    let start = outer.as_ptr() as usize;
+    let position = inner.as_ptr() as usize;
+    if position >= start && position + inner.len() <= start + outer.len() {
+        Some(position - start)
+    } else {
+        None
+    }
+}
+
+impl ParseError {
+    /// `text` is the offending slice of `contents`, which is used to find its
+    /// line and column. Text that isn't part of `contents` is reported at the end.
+    pub fn new(contents: &str, text: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_in(contents, text).unwrap_or(contents.len());
+        let line_start = contents[..offset].rfind('\n').map_or(0, |index| index + 1);
+        let line_end = contents[offset..]
+            .find('\n')
+            .map_or(contents.len(), |index| offset + index);
+
+        ParseError {
+            file: None,
+            line: 1 + contents[..offset].matches('\n').count(),
+            column: 1 + contents[line_start..offset].chars().count(),
+            text: text.to_string(),
+            expected: expected.into(),
+            source_line: contents[line_start..line_end].to_string(),
+        }
+    }
+
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let found = if self.text.is_empty() {
+            String::from("nothing")
+        } else {
+            format!("`{}`", self.text.escape_debug())
+        };
+        writeln!(f, "expected {}, found {}", self.expected, found)?;
+        writeln!(
+            f,
+            " --> {}:{}:{}",
+            self.file.as_deref().unwrap_or("<input>"),
+            self.line,
+            self.column
+        )?;
+
+        let gutter = self.line.to_string().len();
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{:gutter$} | {:indent$}{}",
+            "",
+            "",
+            "^".repeat(self.text.chars().count().max(1)),
+            indent = self.column - 1
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `text`, a slice of `contents`, into a number
+pub fn parse_number<T: FromStr>(contents: &str, text: &str) -> Result<T, ParseError> {
+    text.parse::<T>()
+        .map_err(|_| ParseError::new(contents, text, "a number"))
+}
+
+/// Split `text`, a slice of `contents`, around the first `separator`
+pub fn split_once<'a>(
+    contents: &str,
+    text: &'a str,
+    separator: &str,
+    expected: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator)
+        .ok_or_else(|| ParseError::new(contents, text, expected))
+}
@@ -0,0 +1,231 @@
+use crate::common::Part;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2022";
+
+const USER_AGENT: &str = "advent_of_code_2022 aoc fetch";
+
+/// Settings for talking to the puzzle site, from aoc.toml and the
+/// environment, `AOC_SESSION` and `AOC_BASE_URL` win over the file
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Value of the `session` cookie of a logged in browser
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+pub fn config_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("aoc.toml")
+}
+
+impl Config {
+    /// A missing file is an empty config
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(format!("{}: {}", path.display(), error)),
+        };
+        let mut config: Config =
+            toml::from_str(&contents).map_err(|error| format!("{}: {}", path.display(), error))?;
+
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+}
+
+/// Downloads the pages of a day and posts answers
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        let session = config.session.clone().ok_or_else(|| {
+            String::from("no session token, set AOC_SESSION or `session` in aoc.toml")
+        })?;
+        Ok(Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: config
+                .base_url
+                .as_deref()
+                .unwrap_or(DEFAULT_BASE_URL)
+                .trim_end_matches('/')
+                .to_string(),
+            session,
+        })
+    }
+
+    fn request(&self, method: &str, path: &str) -> (String, ureq::Request) {
+        let url = format!("{}{}", self.base_url, path);
+        let request = self
+            .agent
+            .request(method, &url)
+            .set("Cookie", &format!("session={}", self.session));
+        (url, request)
+    }
+
+    fn read(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|error| format!("{}: {}", url, error)),
+            Err(ureq::Error::Status(status, response)) => {
+                Err(format!("{}: {} {}", url, status, response.status_text()))
+            }
+            Err(error) => Err(format!("{}: {}", url, error)),
+        }
+    }
+
+    fn get(&self, path: &str) -> Result<String, String> {
+        let (url, request) = self.request("GET", path);
+        Client::read(&url, request.call())
+    }
+
+    pub fn input(&self, day: u32) -> Result<String, String> {
+        self.get(&format!("/day/{}/input", day))
+    }
+
+    /// The description as plain text, see `description_text`
+    pub fn description(&self, day: u32) -> Result<String, String> {
+        let url = format!("{}/day/{}", self.base_url, day);
+        description_text(&self.get(&format!("/day/{}", day))?)
+            .ok_or_else(|| format!("{}: no puzzle description on the page", url))
+    }
+
+    /// Posts `answer` to a part of a day and returns the page that judges it
+    pub fn answer(&self, day: u32, part: Part, answer: &str) -> Result<String, String> {
+        let (url, request) = self.request("POST", &format!("/day/{}/answer", day));
+        let level = part.number().to_string();
+        Client::read(
+            &url,
+            request.send_form(&[("level", level.as_str()), ("answer", answer)]),
+        )
+    }
+}
+
+/// `&lt;` and friends, the ones the puzzle pages use
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| &rest[1..end]);
+        let character = match entity {
+            Some("lt") => Some('<'),
+            Some("gt") => Some('>'),
+            Some("amp") => Some('&'),
+            Some("quot") => Some('"'),
+            Some("apos") => Some('\''),
+            Some(entity) => entity
+                .strip_prefix('#')
+                .and_then(|code| code.parse::<u32>().ok())
+                .and_then(char::from_u32),
+            None => None,
+        };
+        match character {
+            Some(character) => {
+                decoded.push(character);
+                rest = &rest[entity.unwrap().len() + 2..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded + rest
+}
+
+/// The puzzle articles of a day's page in the layout of the dayNN/README
+/// files: the `--- Day N ---` headings, one line per paragraph followed by
+/// a blank line, one line per list item and the examples as they are.
+pub fn description_text(html: &str) -> Option<String> {
+    let mut text = String::new();
+    let mut rest = html;
+    let mut found = false;
+
+    while let Some(start) = rest.find("<article class=\"day-desc\">") {
+        found = true;
+        rest = &rest[start..];
+        let end = rest.find("</article>").unwrap_or(rest.len());
+        let mut in_pre = false;
+
+        for (index, piece) in rest[..end].split('<').enumerate() {
+            let (tag, content) = if index == 0 {
+                ("", piece)
+            } else {
+                piece.split_once('>').unwrap_or((piece, ""))
+            };
+            match tag.split_whitespace().next().unwrap_or("") {
+                "/h2" | "/li" => text.push('\n'),
+                "/p" => text.push_str("\n\n"),
+                "pre" => in_pre = true,
+                "/pre" => in_pre = false,
+                _ => {}
+            }
+            // outside of the examples, newlines only separate the tags
+            if in_pre {
+                text.push_str(&decode_entities(content));
+            } else {
+                text.push_str(&decode_entities(&content.replace('\n', "")));
+            }
+        }
+        rest = &rest[end..];
+    }
+
+    found.then(|| text.trim_end().to_string() + "\n")
+}
+
+/// What `fetch` did with a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+/// Writes what `download` gives to `path`, unless `path` already holds
+/// something `complete`
+fn fetch_file(
+    path: PathBuf,
+    complete: fn(&str) -> bool,
+    download: impl FnOnce() -> Result<String, String>,
+) -> Result<(PathBuf, Fetched), String> {
+    let cached = std::fs::read_to_string(&path)
+        .map(|contents| !contents.trim().is_empty() && complete(&contents))
+        .unwrap_or(false);
+    if cached {
+        return Ok((path, Fetched::Cached));
+    }
+
+    std::fs::write(&path, download()?).map_err(|error| format!("{}: {}", path.display(), error))?;
+    Ok((path, Fetched::Downloaded))
+}
+
+/// Downloads the input.txt and README of a day into `directory`, unless
+/// they are there already. A README is only complete with both parts, the
+/// second one shows up after solving the first.
+pub fn fetch(
+    client: &Client,
+    day: u32,
+    directory: &Path,
+) -> Result<Vec<(PathBuf, Fetched)>, String> {
+    Ok(vec![
+        fetch_file(directory.join("input.txt"), |_| true, || client.input(day))?,
+        fetch_file(
+            directory.join("README"),
+            |contents| contents.contains("--- Part Two ---"),
+            || client.description(day),
+        )?,
+    ])
+}
@@ -0,0 +1,27 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Size used when `aoc gen` isn't given one
+pub const DEFAULT_SIZE: usize = 20;
+
+/// Random puzzle inputs in the exact format of the real ones, to test and
+/// benchmark solvers on more than the one input each day ships with. Every
+/// generated input can be solved without panicking.
+pub trait Generate {
+    /// `size` scales the input, what it counts is up to each day, like the
+    /// number of lines or the side of a grid
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String;
+}
+
+/// The same `seed` and `size` always give the same input
+pub fn generate(generator: &dyn Generate, seed: u64, size: usize) -> String {
+    generator.generate(&mut StdRng::seed_from_u64(seed), size)
+}
+
+/// A name of lowercase letters with a length in `lengths`
+pub fn lowercase_name(rng: &mut StdRng, lengths: std::ops::RangeInclusive<usize>) -> String {
+    let length = rng.gen_range(*lengths.start(), *lengths.end() + 1);
+    (0..length)
+        .map(|_| rng.gen_range(b'a', b'z' + 1) as char)
+        .collect()
+}
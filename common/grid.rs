@@ -0,0 +1,241 @@
+use crate::common::point::{BoundingBox, Point};
+use crate::common::ParseError;
+use std::collections::hash_map::{Entry, HashMap};
+use std::ops::{Index, IndexMut};
+
+/// Rectangle of cells stored row by row, `(0, 0)` is the top left
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+            .map(&mut cell)
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// One cell per character, every line has to be as long as the first.
+    /// `cell` gets the position, the character and the character as a slice
+    /// of `contents` for errors.
+    pub fn parse(
+        contents: &str,
+        mut cell: impl FnMut(Point, char, &str) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+        let mut height = 0;
+
+        for (y, line) in contents.lines().enumerate() {
+            let row_start = cells.len();
+            for (x, (index, character)) in line.char_indices().enumerate() {
+                let text = &line[index..index + character.len_utf8()];
+                cells.push(cell(Point::new(x as i32, y as i32), character, text)?);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(
+                        contents,
+                        line,
+                        format!("a row of {} cells like the first", width),
+                    ))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, Point { x, y }: Point) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn index_of(&self, position: Point) -> Option<usize> {
+        if self.contains(position) {
+            Some(position.y as usize * self.width + position.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.index_of(position)
+            .map(move |index| &mut self.cells[index])
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// Cells from `start`, not included, in steps of `step` up to the edge
+    pub fn ray(&self, start: Point, step: Point) -> impl Iterator<Item = (Point, &T)> {
+        std::iter::successors(Some(start), move |&position| Some(position + step))
+            .skip(1)
+            .map_while(move |position| self.get(position).map(|cell| (position, cell)))
+    }
+
+    /// Points next to `position` inside the grid, diagonals not included
+    pub fn neighbours4(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        position
+            .neighbours4()
+            .filter(move |neighbour| self.contains(*neighbour))
+    }
+
+    /// Points next to `position` inside the grid, diagonals included
+    pub fn neighbours8(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        position
+            .neighbours8()
+            .filter(move |neighbour| self.contains(*neighbour))
+    }
+}
+
+/// Panics outside the grid, `get` is the checked version
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &T {
+        match self.index_of(position) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "{:?} is outside the {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut T {
+        match self.index_of(position) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "{:?} is outside the {}x{} grid",
+                position, self.width, self.height
+            ),
+        }
+    }
+}
+
+/// Cells of an unbounded space, only the ones that were set are stored.
+/// The bounds grow to every position that was ever set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<BoundingBox>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, position: Point) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        self.cells.get_mut(&position)
+    }
+
+    fn grow(&mut self, position: Point) {
+        self.bounds = Some(match self.bounds {
+            Some(bounds) => bounds.including(position),
+            None => BoundingBox::new(position, position),
+        });
+    }
+
+    pub fn insert(&mut self, position: Point, value: T) -> Option<T> {
+        self.grow(position);
+        self.cells.insert(position, value)
+    }
+
+    pub fn entry(&mut self, position: Point) -> Entry<'_, Point, T> {
+        self.grow(position);
+        self.cells.entry(position)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(position, cell)| (*position, cell))
+    }
+
+    /// Box around the cells set so far
+    pub fn bounds(&self) -> Option<BoundingBox> {
+        self.bounds
+    }
+}
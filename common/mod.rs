@@ -0,0 +1,34 @@
+pub mod answer;
+pub mod args;
+pub mod canvas;
+pub mod days;
+pub mod differential;
+pub mod error;
+pub mod fetch;
+pub mod generate;
+pub mod grid;
+pub mod output;
+pub mod point;
+pub mod point3;
+pub mod puzzle;
+pub mod readme;
+pub mod registry;
+pub mod render;
+pub mod scaffold;
+pub mod solver;
+pub mod submit;
+pub mod terminal;
+pub mod timing;
+
+pub use answer::Answer;
+pub use args::Args;
+pub use error::ParseError;
+pub use generate::Generate;
+pub use grid::{Grid, SparseGrid};
+pub use output::Format;
+pub use point::{BoundingBox, Direction, Direction8, Point};
+pub use point3::{BoundingBox3, Direction3, Point3};
+pub use puzzle::{input_name, read_input, run, Puzzle};
+pub use registry::Registry;
+pub use render::{run_visual, Recorder, Visualize};
+pub use solver::{Part, Report, Solution, Solver};
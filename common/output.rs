@@ -0,0 +1,62 @@
+use crate::common::{Answer, Report};
+use serde::Serialize;
+
+/// How answers are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// For people reading a terminal
+    Text,
+    /// One JSON object per solved part and line
+    Json,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+
+    /// Read the `--format` option, text when it is not given
+    pub fn from_option(option: Option<&str>) -> Result<Self, String> {
+        option.map_or(Ok(Format::Text), |name| {
+            Format::from_name(name)
+                .ok_or_else(|| format!("`{}` is not a format, expected text or json", name))
+        })
+    }
+}
+
+/// A solved part as written by `--format json`
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub day: u32,
+    pub part: u32,
+    pub answer: &'a Answer,
+    /// `number`, `string` or `image`
+    pub kind: &'static str,
+    pub parse_time_ns: u128,
+    pub solve_time_ns: u128,
+    pub input: &'a str,
+}
+
+/// One line of JSON for every part in `report`
+pub fn json_lines(day: u32, input: &str, report: &Report) -> Vec<String> {
+    report
+        .solutions
+        .iter()
+        .map(|solution| {
+            let record = Record {
+                day,
+                part: solution.part.number(),
+                answer: &solution.answer,
+                kind: solution.answer.kind(),
+                parse_time_ns: report.parse_time.as_nanos(),
+                solve_time_ns: solution.elapsed.as_nanos(),
+                input,
+            };
+            serde_json::to_string(&record).expect("records always serialize")
+        })
+        .collect()
+}
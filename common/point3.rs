@@ -0,0 +1,160 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+
+/// A position or an offset in space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// Steps between the points moving only along the axes
+    pub fn manhattan(self, other: Point3) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// The points sharing a face with this one
+    pub fn neighbours6(self) -> impl Iterator<Item = Point3> {
+        Direction3::ALL
+            .into_iter()
+            .map(move |direction| self + direction.step())
+    }
+}
+
+impl fmt::Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+impl From<(i32, i32, i32)> for Point3 {
+    fn from((x, y, z): (i32, i32, i32)) -> Self {
+        Point3::new(x, y, z)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, other: Point3) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl SubAssign for Point3 {
+    fn sub_assign(&mut self, other: Point3) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Point3;
+
+    fn neg(self) -> Point3 {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// The six directions along the axes, one through each face of a cube
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction3 {
+    Left,
+    Right,
+    Down,
+    Up,
+    Back,
+    Front,
+}
+
+impl Direction3 {
+    /// Both ways along x, then y, then z
+    pub const ALL: [Direction3; 6] = [
+        Direction3::Left,
+        Direction3::Right,
+        Direction3::Down,
+        Direction3::Up,
+        Direction3::Back,
+        Direction3::Front,
+    ];
+
+    pub fn step(self) -> Point3 {
+        match self {
+            Direction3::Left => Point3::new(-1, 0, 0),
+            Direction3::Right => Point3::new(1, 0, 0),
+            Direction3::Down => Point3::new(0, -1, 0),
+            Direction3::Up => Point3::new(0, 1, 0),
+            Direction3::Back => Point3::new(0, 0, -1),
+            Direction3::Front => Point3::new(0, 0, 1),
+        }
+    }
+}
+
+/// Smallest box holding a set of points, both corners included
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox3 {
+    pub min: Point3,
+    pub max: Point3,
+}
+
+impl BoundingBox3 {
+    pub fn new(min: Point3, max: Point3) -> Self {
+        BoundingBox3 { min, max }
+    }
+
+    /// `None` when there are no points
+    pub fn from_points(points: impl IntoIterator<Item = Point3>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(BoundingBox3::new(first, first), BoundingBox3::including))
+    }
+
+    /// Grown to hold `point` as well
+    pub fn including(self, point: Point3) -> Self {
+        BoundingBox3 {
+            min: Point3::new(
+                self.min.x.min(point.x),
+                self.min.y.min(point.y),
+                self.min.z.min(point.z),
+            ),
+            max: Point3::new(
+                self.max.x.max(point.x),
+                self.max.y.max(point.y),
+                self.max.z.max(point.z),
+            ),
+        }
+    }
+
+    /// Grown by `margin` on every side
+    pub fn grown(self, margin: i32) -> Self {
+        let margin = Point3::new(margin, margin, margin);
+        BoundingBox3::new(self.min - margin, self.max + margin)
+    }
+
+    pub fn contains(&self, point: Point3) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x)
+            && (self.min.y..=self.max.y).contains(&point.y)
+            && (self.min.z..=self.max.z).contains(&point.z)
+    }
+}
@@ -0,0 +1,154 @@
+use crate::common::output::{self, Format};
+use crate::common::{Answer, Args, ParseError, Part, Report, Solver};
+use std::env;
+use std::io::{self, Read};
+use std::process;
+
+/// A day of the calendar. The input is parsed once and both parts are solved
+/// from the parsed value, so parsers and solvers can be reused on their own.
+pub trait Puzzle {
+    /// Day of the calendar, 1 to 25
+    const DAY: u32;
+
+    type Input;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError>;
+    fn part1(&self, input: &Self::Input) -> Self::Output1;
+    fn part2(&self, input: &Self::Input) -> Self::Output2;
+}
+
+/// Read a puzzle input, `-` reads standard input
+pub fn read_input(inputfile: &str) -> io::Result<String> {
+    if inputfile == "-" {
+        let mut contents = String::new();
+        io::stdin().read_to_string(&mut contents)?;
+        Ok(contents)
+    } else {
+        std::fs::read_to_string(inputfile)
+    }
+}
+
+/// Name of an input in diagnostics
+pub fn input_name(inputfile: &str) -> &str {
+    if inputfile == "-" {
+        "<stdin>"
+    } else {
+        inputfile
+    }
+}
+
+/// Command line of a day binary
+pub(crate) struct Command {
+    program: String,
+    /// Usage of the options besides `--format`
+    usage: String,
+    pub(crate) args: Args,
+    pub(crate) format: Format,
+}
+
+impl Command {
+    /// Read the command line, accepting `--format` and `options`
+    pub(crate) fn parse(options: &[&str], usage: &str) -> Self {
+        let mut command = Command {
+            program: env::args().next().unwrap_or_default(),
+            usage: usage.to_string(),
+            args: Args::default(),
+            format: Format::Text,
+        };
+
+        command.args =
+            Args::parse(env::args().skip(1)).unwrap_or_else(|error| command.fail(&error));
+        let known = [&["format"], options].concat();
+        command
+            .args
+            .check_options(&known)
+            .unwrap_or_else(|error| command.fail(&error));
+        command.format = Format::from_option(command.args.option("format"))
+            .unwrap_or_else(|error| command.fail(&error));
+        if command.args.positional().is_empty() {
+            command.fail("missing input file");
+        }
+        command
+    }
+
+    /// Invalid command line
+    pub(crate) fn fail(&self, message: &str) -> ! {
+        eprintln!(
+            "{}: {}\n\nUsage: {} [--format text|json]{} <inputfile|->...",
+            self.program, message, self.program, self.usage
+        );
+        process::exit(2);
+    }
+
+    /// Value of a numeric option, which has to be positive
+    pub(crate) fn number(&self, name: &str) -> Option<u32> {
+        self.args
+            .option(name)
+            .map(|value| match value.parse::<u32>() {
+                Ok(number) if number > 0 => number,
+                _ => self.fail(&format!(
+                    "`{}` is not a positive number for --{}",
+                    value, name
+                )),
+            })
+    }
+
+    /// Solve every input file in turn and print the answers, returns whether
+    /// all inputs could be read and parsed
+    pub(crate) fn solve_inputs(
+        &self,
+        day: u32,
+        mut solve: impl FnMut(&str) -> Result<Report, ParseError>,
+    ) -> bool {
+        let inputfiles = self.args.positional();
+        let mut failed = false;
+        for inputfile in inputfiles {
+            let name = input_name(inputfile);
+            if inputfiles.len() > 1 && self.format == Format::Text {
+                println!("==> {} <==", name);
+            }
+
+            let report = read_input(inputfile)
+                .map_err(|error| format!("{}: {}", name, error))
+                .and_then(|contents| {
+                    solve(&contents).map_err(|error| error.with_file(name).to_string())
+                });
+            match report {
+                Ok(report) if self.format == Format::Json => {
+                    for line in output::json_lines(day, name, &report) {
+                        println!("{}", line);
+                    }
+                }
+                Ok(report) => {
+                    for solution in report.solutions {
+                        match solution.answer {
+                            // start images on their own line so the rows line up
+                            Answer::Image(image) => {
+                                println!("Part{}:\n{}", solution.part, image)
+                            }
+                            answer => println!("Part{}: {}", solution.part, answer),
+                        }
+                    }
+                }
+                Err(error) => {
+                    eprintln!("error: {}", error);
+                    failed = true;
+                }
+            }
+        }
+        !failed
+    }
+}
+
+/// Entry point shared by the day binaries: solve every input file given as an
+/// argument in turn, as text or as JSON with `--format json`. Unreadable or
+/// malformed inputs are reported and make the process exit with a failure
+/// once all inputs have been tried.
+pub fn run<P: Puzzle>(puzzle: &P) {
+    let command = Command::parse(&[], "");
+    if !command.solve_inputs(P::DAY, |contents| puzzle.solve(contents, &Part::BOTH)) {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,209 @@
+use crate::common::days;
+use crate::common::registry::Answers;
+use crate::common::Part;
+use std::ops::Range;
+use std::path::PathBuf;
+
+/// Heading that starts the second half of a puzzle description
+const PART_TWO: &str = "--- Part Two ---";
+
+/// Days whose small input isn't the example in the description: day 10 has
+/// a shorter program, day 13 has the divider packets appended
+pub const OWN_SMALL_INPUTS: [u32; 2] = [10, 13];
+
+/// The example input of a puzzle description and the answers it states
+#[derive(Debug, Default, Clone)]
+pub struct Example {
+    pub input: String,
+    pub answers: Answers,
+}
+
+pub fn readme_path(day: u32) -> PathBuf {
+    days::day_directory(day).join("README")
+}
+
+/// A sentence of the description, as opposed to a line of an example,
+/// a diagram or a listing
+fn is_prose(line: &str) -> bool {
+    let words = line
+        .split_whitespace()
+        .filter(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .chars()
+                .all(|c| c.is_alphabetic() || c == '\'')
+        })
+        .count();
+    line.starts_with(|c: char| c.is_ascii_uppercase()) && words >= 4
+}
+
+/// A sentence that introduces the block below it, some are just "For example:"
+fn is_intro(line: &str) -> bool {
+    (is_prose(line) && line.ends_with(':')) || line == "For example:"
+}
+
+/// The lines after the intro at `intro` up to the next sentence, without
+/// the blank lines around them
+fn block(lines: &[&str], intro: usize) -> Range<usize> {
+    let start = (intro + 1..lines.len())
+        .find(|&index| !lines[index].is_empty())
+        .unwrap_or(lines.len());
+    let mut end = (start..lines.len())
+        .find(|&index| is_prose(lines[index]))
+        .unwrap_or(lines.len());
+    while end > start && lines[end - 1].is_empty() {
+        end -= 1;
+    }
+    start..end
+}
+
+/// The last number or capitalized word of a sentence, leaving out the
+/// asides in parentheses, like 15 in "a total score of 15 (8 + 1 + 6)."
+fn stated_answer(line: &str) -> Option<String> {
+    let mut depth = 0;
+    let outside = line
+        .chars()
+        .filter(|&c| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => return depth == 0,
+            }
+            false
+        })
+        .collect::<String>();
+
+    outside
+        .split_whitespace()
+        .map(|word| word.trim_end_matches(|c: char| c.is_ascii_punctuation()))
+        .rev()
+        .find(|word| {
+            let digits = word.strip_prefix('-').unwrap_or(word);
+            (!digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()))
+                || (word.len() > 1 && word.chars().all(|c| c.is_ascii_uppercase()))
+        })
+        .map(str::to_string)
+}
+
+/// Lines like `<input>: first marker after character 7` right above the
+/// question, as the input and the number at the end
+fn listed_answers<'a>(lines: &[&'a str], question: usize) -> Vec<(&'a str, &'a str)> {
+    let mut listed = lines[..question]
+        .iter()
+        .rev()
+        .map_while(|line| {
+            let (input, rest) = line.split_once(": ")?;
+            let answer = rest.rsplit(' ').next()?;
+            let listed = !input.is_empty()
+                && !input.contains(char::is_whitespace)
+                && answer.chars().all(|c| c.is_ascii_digit());
+            listed.then_some((input, answer))
+        })
+        .collect::<Vec<_>>();
+    listed.reverse();
+    if listed.len() > 1 {
+        listed
+    } else {
+        Vec::new()
+    }
+}
+
+/// Rows of `#` and `.` right above the question, for puzzles where the
+/// answer is read off an image
+fn image_answer(lines: &[&str], question: usize) -> Option<String> {
+    let rows = lines[..question]
+        .iter()
+        .rev()
+        .take_while(|line| !line.is_empty() && line.chars().all(|c| c == '#' || c == '.'))
+        .collect::<Vec<_>>();
+    if rows.is_empty() {
+        return None;
+    }
+    Some(
+        rows.into_iter()
+            .rev()
+            .copied()
+            .collect::<Vec<&str>>()
+            .join("\n"),
+    )
+}
+
+/// Finds the example of a puzzle description (the first block introduced
+/// "For example:") and the answers stated for it before each question.
+///
+/// A list of several inputs with their answers right above a question, like
+/// the datastreams of day 6, makes the last of them the example instead.
+/// Answers stated after a larger example is introduced belong to that one
+/// and are skipped. When a question asks for letters, the answer is the
+/// image drawn right above it.
+pub fn extract(readme: &str) -> Result<Example, String> {
+    // trailing spaces are kept in the example, like in the crate drawing of day 5
+    let raw_lines = readme.lines().collect::<Vec<&str>>();
+    let lines = raw_lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<&str>>();
+    let intros = (0..lines.len())
+        .filter(|&index| is_intro(lines[index]) && !block(&lines, index).is_empty())
+        .collect::<Vec<usize>>();
+    let intro = intros
+        .iter()
+        .find(|&&index| lines[index].to_lowercase().contains("for example"))
+        .or_else(|| intros.first())
+        .copied()
+        .ok_or_else(|| String::from("no example in the description"))?;
+    let mut example = Example {
+        input: raw_lines[block(&lines, intro)].join("\n") + "\n",
+        answers: Answers::default(),
+    };
+
+    let larger_example = intros.iter().copied().find(|&index| {
+        let line = lines[index].to_lowercase();
+        index > intro && line.contains("larger example") && !line.contains("above")
+    });
+    let part_two = lines
+        .iter()
+        .position(|line| *line == PART_TWO)
+        .unwrap_or(lines.len());
+
+    let mut listed_input = None;
+    for (part, range) in Part::BOTH
+        .into_iter()
+        .zip([0..part_two, part_two..lines.len()])
+    {
+        let question = match range
+            .clone()
+            .rev()
+            .find(|&index| lines[index].ends_with('?'))
+        {
+            Some(question) => question,
+            None => continue,
+        };
+
+        let listed = listed_answers(&lines, question);
+        let answer = if let Some((last, _)) = listed.last() {
+            let input = *listed_input.get_or_insert(*last);
+            listed
+                .iter()
+                .find(|(listed, _)| *listed == input)
+                .map(|(_, answer)| answer.to_string())
+        } else if lines[question].contains("letters") {
+            image_answer(&lines, question)
+        } else {
+            let end = larger_example
+                .filter(|index| range.contains(index) && *index < question)
+                .unwrap_or(question);
+            (range.start..end)
+                .rev()
+                .filter(|&index| is_prose(lines[index]))
+                .find_map(|index| stated_answer(lines[index]))
+        };
+        if let Some(answer) = answer {
+            example.answers.set(part, answer);
+        }
+    }
+
+    if let Some(input) = listed_input {
+        example.input = format!("{}\n", input);
+    }
+    Ok(example)
+}
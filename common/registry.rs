@@ -0,0 +1,164 @@
+use crate::common::days::{self, InputKind};
+use crate::common::Part;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Expected answers for one input of a day
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+}
+
+/// Known answers, stored as `[dayNN.<small|full>]` tables with a `part1` and `part2` key
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Registry {
+    days: BTreeMap<String, BTreeMap<String, Answers>>,
+}
+
+/// Outcome of solving one registered answer
+#[derive(Debug)]
+pub struct Check {
+    pub day: u32,
+    pub kind: InputKind,
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+    pub elapsed: Duration,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+pub fn registry_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+/// Comment at the top of answers.toml
+const HEADER: &str = "\
+# Known answers per day and input, checked by `aoc verify` and `cargo test`.
+# `small` is dayNN/small_input.txt and `full` is dayNN/input.txt.
+";
+
+fn day_key(day: u32) -> String {
+    format!("day{:02}", day)
+}
+
+/// A TOML string holding `answer`
+fn quote(answer: &str) -> String {
+    if answer.contains('\n') && !answer.contains("'''") {
+        format!("'''\n{}'''", answer)
+    } else {
+        format!("{:?}", answer)
+    }
+}
+
+impl Registry {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+        toml::from_str(&contents).map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_toml())
+            .map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    /// The registry laid out like answers.toml: days in order, the small
+    /// input before the full one and multi line answers as literal strings
+    pub fn to_toml(&self) -> String {
+        let mut contents = String::from(HEADER);
+        for (day, inputs) in &self.days {
+            let inputs = inputs.iter().sorted_by_key(|(name, _)| {
+                InputKind::ALL
+                    .iter()
+                    .position(|kind| kind.name() == name.as_str())
+                    .unwrap_or(InputKind::ALL.len())
+            });
+            for (name, answers) in inputs {
+                contents.push_str(&format!("\n[{}.{}]\n", day, name));
+                for part in Part::BOTH {
+                    if let Some(answer) = answers.get(part) {
+                        contents.push_str(&format!("part{} = {}\n", part.number(), quote(answer)));
+                    }
+                }
+            }
+        }
+        contents
+    }
+
+    pub fn answers(&self, day: u32, kind: InputKind) -> Option<&Answers> {
+        self.days.get(&day_key(day))?.get(kind.name())
+    }
+
+    pub fn insert(&mut self, day: u32, kind: InputKind, part: Part, answer: String) {
+        self.days
+            .entry(day_key(day))
+            .or_default()
+            .entry(kind.name().to_string())
+            .or_default()
+            .set(part, answer);
+    }
+
+    /// Solve every registered part of `day` for the given input and compare
+    /// the results against the registry.
+    pub fn check(&self, day: u32, kind: InputKind) -> Result<Vec<Check>, String> {
+        let answers = match self.answers(day, kind) {
+            Some(answers) => answers,
+            None => return Ok(Vec::new()),
+        };
+        let parts = Part::BOTH
+            .into_iter()
+            .filter(|part| answers.get(*part).is_some())
+            .collect::<Vec<Part>>();
+
+        let path = days::input_path(day, kind);
+        let contents = std::fs::read_to_string(&path)
+            .map_err(|error| format!("{}: {}", path.display(), error))?;
+        let puzzle =
+            days::puzzle_for(day, kind).ok_or_else(|| format!("no puzzle for day {}", day))?;
+
+        let report = puzzle
+            .solve(&contents, &parts)
+            .map_err(|error| error.with_file(path.display().to_string()).to_string())?;
+
+        Ok(report
+            .solutions
+            .into_iter()
+            .map(|solution| Check {
+                day,
+                kind,
+                part: solution.part,
+                expected: answers.get(solution.part).unwrap().to_string(),
+                actual: solution.answer.to_string(),
+                elapsed: solution.elapsed,
+            })
+            .collect())
+    }
+}
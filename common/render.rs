@@ -0,0 +1,522 @@
+use crate::common::canvas::{Canvas, Color};
+use crate::common::puzzle::Command;
+use crate::common::solver::{measure, solve_part};
+use crate::common::terminal::Terminal;
+use crate::common::{Answer, Part, Puzzle, Solver};
+use image::{imageops, Rgb, RgbImage};
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+/// How often an animation plays
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Looping {
+    Forever,
+    Times(u16),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnimationOptions {
+    /// Time each frame is shown
+    pub delay: Duration,
+    pub looping: Looping,
+}
+
+impl Default for AnimationOptions {
+    fn default() -> Self {
+        AnimationOptions {
+            delay: Duration::from_millis(100),
+            looping: Looping::Forever,
+        }
+    }
+}
+
+/// Frames that could not be written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderError {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+impl RenderError {
+    fn new(path: &Path, reason: impl fmt::Display) -> Self {
+        RenderError {
+            path: path.to_path_buf(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.reason)
+    }
+}
+
+impl std::error::Error for RenderError {}
+
+enum Target {
+    Off,
+    /// One `<prefix>.frameNNNNN.png` per frame
+    Directory {
+        prefix: String,
+    },
+    /// Streamed, the encoder is created once the first frame gives the size
+    Gif {
+        file: Option<BufWriter<File>>,
+        encoder: Option<gif::Encoder<BufWriter<File>>>,
+    },
+    /// Drawn in place in the terminal
+    Terminal(Terminal),
+    /// Streamed like a GIF, the frame count in the header is only known at
+    /// the end and written into it then
+    Apng {
+        file: File,
+        writer: Option<png::Writer<BufWriter<File>>>,
+    },
+}
+
+/// Destination for the frames drawn by a visualization.
+///
+/// Drawing code asks `step` whether the current step is wanted before it
+/// builds a canvas, which keeps sampled and stopped recordings cheap. It then
+/// calls `record` and carries on, the first failure stops the recording and
+/// is returned by `finish`. Every frame of an animation has the
+/// size of the first one, later frames are padded or cropped to fit, so
+/// renderers should prefer fixed canvas bounds.
+pub struct Recorder {
+    target: Target,
+    path: PathBuf,
+    options: AnimationOptions,
+    size: Option<(u32, u32)>,
+    every: u64,
+    max_frames: Option<u32>,
+    steps: u64,
+    frames: u32,
+    /// Drawing was stopped from the terminal
+    stopped: bool,
+    error: Option<RenderError>,
+}
+
+impl Recorder {
+    fn new(target: Target, path: &Path, options: AnimationOptions) -> Self {
+        Recorder {
+            target,
+            path: path.to_path_buf(),
+            options,
+            size: None,
+            every: 1,
+            max_frames: None,
+            steps: 0,
+            frames: 0,
+            stopped: false,
+            error: None,
+        }
+    }
+
+    /// Draw nothing, used when solving without a visualization
+    pub fn off() -> Self {
+        Recorder::new(Target::Off, Path::new(""), AnimationOptions::default())
+    }
+
+    /// Write numbered PNG frames into `directory`, which is created if needed
+    pub fn directory(directory: impl AsRef<Path>, prefix: &str) -> Result<Self, RenderError> {
+        let directory = directory.as_ref();
+        fs::create_dir_all(directory).map_err(|error| RenderError::new(directory, error))?;
+        Ok(Recorder::new(
+            Target::Directory {
+                prefix: prefix.to_string(),
+            },
+            directory,
+            AnimationOptions::default(),
+        ))
+    }
+
+    /// Write a single animation, a GIF for `.gif` files and an APNG for
+    /// `.png` or `.apng` files. Missing parent directories are created.
+    pub fn animation(
+        path: impl AsRef<Path>,
+        options: AnimationOptions,
+    ) -> Result<Self, RenderError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        let create = || {
+            if let Some(directory) = path
+                .parent()
+                .filter(|parent| !parent.as_os_str().is_empty())
+            {
+                fs::create_dir_all(directory)
+                    .map_err(|error| RenderError::new(directory, error))?;
+            }
+            // APNGs are read back to fill in the frame count
+            OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(path)
+                .map_err(|error| RenderError::new(path, error))
+        };
+
+        let target = match extension.as_deref() {
+            Some("gif") => Target::Gif {
+                file: Some(BufWriter::new(create()?)),
+                encoder: None,
+            },
+            Some("png") | Some("apng") => Target::Apng {
+                file: create()?,
+                writer: None,
+            },
+            _ => {
+                return Err(RenderError::new(
+                    path,
+                    "unknown animation format, expected a .gif, .png or .apng file",
+                ))
+            }
+        };
+        Ok(Recorder::new(target, path, options))
+    }
+
+    /// Draw in the terminal, showing a frame every `delay` at most
+    pub fn terminal(delay: Duration) -> Self {
+        Recorder::new(
+            Target::Terminal(Terminal::new(delay)),
+            Path::new(TERMINAL),
+            AnimationOptions::default(),
+        )
+    }
+
+    /// The terminal for `terminal`, an animation for `.gif`, `.png` or
+    /// `.apng` paths and a directory of numbered PNG frames starting with
+    /// `prefix` otherwise
+    pub fn create(
+        path: impl AsRef<Path>,
+        prefix: &str,
+        options: AnimationOptions,
+    ) -> Result<Self, RenderError> {
+        let path = path.as_ref();
+        if path == Path::new(TERMINAL) {
+            return Ok(Recorder::terminal(options.delay));
+        }
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("gif") | Some("png") | Some("apng") => Recorder::animation(path, options),
+            _ => Recorder::directory(path, prefix),
+        }
+    }
+
+    /// Only draw every `every`th step
+    pub fn every(mut self, every: u32) -> Self {
+        self.every = every.max(1) as u64;
+        self
+    }
+
+    /// Stop drawing after `max_frames` frames, solving carries on
+    pub fn max_frames(mut self, max_frames: u32) -> Self {
+        self.max_frames = Some(max_frames);
+        self
+    }
+
+    fn is_recording(&self) -> bool {
+        !matches!(self.target, Target::Off)
+            && !self.stopped
+            && self.error.is_none()
+            && self.max_frames.is_none_or(|max| self.frames < max)
+    }
+
+    /// Move the visualization one step on, returns whether this step should
+    /// be drawn and passed to `record`
+    pub fn step(&mut self) -> bool {
+        if !self.is_recording() {
+            return false;
+        }
+        let wanted = self.steps.is_multiple_of(self.every);
+        self.steps += 1;
+        wanted
+    }
+
+    /// Number of frames recorded so far
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    pub fn record(&mut self, canvas: &Canvas) {
+        if !self.is_recording() {
+            return;
+        }
+
+        if let Target::Terminal(terminal) = &mut self.target {
+            // one character per cell, the canvas scale is for images
+            match terminal.show(&canvas.render_scaled(1), self.frames) {
+                Ok(shown) => self.stopped = !shown,
+                Err(error) => self.error = Some(RenderError::new(&self.path, error)),
+            }
+            self.frames += 1;
+            return;
+        }
+
+        let image = canvas.render();
+        let (width, height) = *self.size.get_or_insert(image.dimensions());
+        let image = if image.dimensions() == (width, height) {
+            image
+        } else {
+            fit(&image, width, height, canvas.background_color())
+        };
+
+        if let Err(error) = self.write(image) {
+            self.error = Some(error);
+        }
+        self.frames += 1;
+    }
+
+    fn write(&mut self, image: RgbImage) -> Result<(), RenderError> {
+        let path = &self.path;
+        match &mut self.target {
+            Target::Off | Target::Terminal(_) => Ok(()),
+            Target::Directory { prefix } => {
+                let file = path.join(format!("{}.frame{:05}.png", prefix, self.frames));
+                image
+                    .save(&file)
+                    .map_err(|error| RenderError::new(&file, error))
+            }
+            Target::Gif { file, encoder } => {
+                let fail = |error: gif::EncodingError| RenderError::new(path, error);
+                if encoder.is_none() {
+                    let (width, height) = gif_size(path, image.dimensions())?;
+                    let mut started = gif::Encoder::new(file.take().unwrap(), width, height, &[])
+                        .map_err(fail)?;
+                    let repeat = match self.options.looping {
+                        Looping::Forever => gif::Repeat::Infinite,
+                        Looping::Times(times) => gif::Repeat::Finite(times.saturating_sub(1)),
+                    };
+                    started.set_repeat(repeat).map_err(fail)?;
+                    *encoder = Some(started);
+                }
+
+                let (width, height) = gif_size(path, image.dimensions())?;
+                let mut frame = gif::Frame::from_rgb_speed(width, height, image.as_raw(), 10);
+                // GIF delays are in hundredths of a second
+                frame.delay = (self.options.delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+                encoder.as_mut().unwrap().write_frame(&frame).map_err(fail)
+            }
+            Target::Apng { file, writer } => {
+                let fail = |error: png::EncodingError| RenderError::new(path, error);
+                if writer.is_none() {
+                    let file = file
+                        .try_clone()
+                        .map_err(|error| RenderError::new(path, error))?;
+                    *writer = Some(start_apng(file, &image, &self.options).map_err(fail)?);
+                }
+                writer
+                    .as_mut()
+                    .unwrap()
+                    .write_image_data(image.as_raw())
+                    .map_err(fail)
+            }
+        }
+    }
+
+    /// Complete the output and return the number of frames written. An
+    /// animation without frames is an error and its file is removed.
+    pub fn finish(mut self) -> Result<u32, RenderError> {
+        if let Some(error) = self.error.take() {
+            if let Target::Terminal(terminal) = &mut self.target {
+                let _ = terminal.finish();
+            }
+            return Err(error);
+        }
+
+        let path = self.path.clone();
+        let fail = |error: &dyn fmt::Display| RenderError::new(&path, error);
+        let empty = || {
+            let _ = fs::remove_file(&path);
+            RenderError::new(&path, "nothing was drawn, an animation needs a frame")
+        };
+        match self.target {
+            Target::Off | Target::Directory { .. } => {}
+            Target::Terminal(mut terminal) => {
+                terminal.finish().map_err(|error| fail(&error))?;
+            }
+            Target::Gif { encoder, .. } => {
+                encoder
+                    .ok_or_else(empty)?
+                    .into_inner()
+                    .map_err(|error| fail(&error))?
+                    .flush()
+                    .map_err(|error| fail(&error))?;
+            }
+            Target::Apng { mut file, writer } => {
+                writer
+                    .ok_or_else(empty)?
+                    .finish()
+                    .map_err(|error| fail(&error))?;
+                set_apng_frames(&mut file, self.frames).map_err(|error| fail(&error))?;
+            }
+        }
+        Ok(self.frames)
+    }
+}
+
+/// A puzzle that can draw its progress while solving a part
+pub trait Visualize: Puzzle {
+    fn visualize(&self, input: &Self::Input, part: Part, recorder: &mut Recorder) -> Answer;
+}
+
+/// `--render` target that draws in the terminal
+pub const TERMINAL: &str = "terminal";
+
+const USAGE: &str = " [--render <dir|file|terminal> [--part 1|2] [--every N] [--max-frames N] \
+                     [--delay MS|--fps N]]";
+
+/// Entry point of the day binaries that can draw: like `run`, and with
+/// `--render` one part is drawn into a directory of PNG frames, into a
+/// `.gif`, `.png` or `.apng` animation or live in the terminal. Without
+/// `--render` nothing is drawn.
+pub fn run_visual<V: Visualize>(puzzle: &V) {
+    let command = Command::parse(
+        &["render", "part", "every", "max-frames", "delay", "fps"],
+        USAGE,
+    );
+    let target = match command.args.option("render") {
+        Some(target) => target,
+        None => {
+            for option in ["part", "every", "max-frames", "delay", "fps"] {
+                if command.args.option(option).is_some() {
+                    command.fail(&format!("--{} needs --render", option));
+                }
+            }
+            if !command.solve_inputs(V::DAY, |contents| puzzle.solve(contents, &Part::BOTH)) {
+                process::exit(1);
+            }
+            return;
+        }
+    };
+    if command.args.positional().len() > 1 {
+        command.fail("--render draws a single input file");
+    }
+
+    let part = command
+        .number("part")
+        .map_or(Some(Part::One), Part::from_number)
+        .unwrap_or_else(|| command.fail("--part has to be 1 or 2"));
+    let mut options = AnimationOptions::default();
+    match (command.number("delay"), command.number("fps")) {
+        (Some(_), Some(_)) => command.fail("--delay and --fps both set the frame rate"),
+        (Some(delay), None) => options.delay = Duration::from_millis(delay as u64),
+        (None, Some(fps)) => options.delay = Duration::from_secs(1) / fps,
+        (None, None) => {}
+    }
+    let mut recorder = Recorder::create(target, &format!("day{:02}", V::DAY), options)
+        .unwrap_or_else(|error| {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        });
+    if let Some(every) = command.number("every") {
+        recorder = recorder.every(every);
+    }
+    if let Some(max_frames) = command.number("max-frames") {
+        recorder = recorder.max_frames(max_frames);
+    }
+
+    let solved = command.solve_inputs(V::DAY, |contents| {
+        measure(
+            || puzzle.parse(contents),
+            &Part::BOTH,
+            |input, solving| {
+                if solving == part {
+                    puzzle.visualize(input, part, &mut recorder)
+                } else {
+                    solve_part(puzzle, input, solving)
+                }
+            },
+        )
+    });
+    match recorder.finish() {
+        Ok(frames) => eprintln!("rendered {} frames of part {} to {}", frames, part, target),
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(1);
+        }
+    }
+    if !solved {
+        process::exit(1);
+    }
+}
+
+/// `image` placed at the top left of a `width` × `height` frame
+fn fit(image: &RgbImage, width: u32, height: u32, background: Color) -> RgbImage {
+    let mut frame = RgbImage::from_pixel(width, height, Rgb(background));
+    imageops::replace(&mut frame, image, 0, 0);
+    frame
+}
+
+fn gif_size(path: &Path, (width, height): (u32, u32)) -> Result<(u16, u16), RenderError> {
+    match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(width), Ok(height)) => Ok((width, height)),
+        _ => Err(RenderError::new(
+            path,
+            format!("a {}×{} frame is too large for a GIF", width, height),
+        )),
+    }
+}
+
+/// Frame count of an APNG before the last frame is written, replaced by
+/// `set_apng_frames`
+const UNKNOWN_FRAMES: u32 = u32::MAX;
+
+/// Write the header of an APNG the size of `first`, ready for its frames
+fn start_apng(
+    file: File,
+    first: &RgbImage,
+    options: &AnimationOptions,
+) -> Result<png::Writer<BufWriter<File>>, png::EncodingError> {
+    let mut encoder = png::Encoder::new(BufWriter::new(file), first.width(), first.height());
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let plays = match options.looping {
+        Looping::Forever => 0,
+        Looping::Times(times) => times.max(1) as u32,
+    };
+    encoder.set_animated(UNKNOWN_FRAMES, plays)?;
+    let delay = options.delay.as_millis().min(u16::MAX as u128) as u16;
+    encoder.set_frame_delay(delay, 1000)?;
+    encoder.write_header()
+}
+
+/// Put the number of `frames` in the `acTL` chunk of a finished APNG
+fn set_apng_frames(file: &mut File, frames: u32) -> io::Result<()> {
+    // chunks start after the 8 byte signature, each with its length and type
+    file.seek(SeekFrom::Start(8))?;
+    loop {
+        let mut header = [0; 8];
+        file.read_exact(&mut header)?;
+        let (length, kind) = header.split_at(4);
+        if kind != b"acTL" {
+            let length = u32::from_be_bytes(length.try_into().unwrap());
+            // the data and the CRC after it
+            file.seek(SeekFrom::Current(length as i64 + 4))?;
+            continue;
+        }
+
+        // the frame count and the number of plays
+        let mut data = [0; 8];
+        file.read_exact(&mut data)?;
+        data[..4].copy_from_slice(&frames.to_be_bytes());
+        let mut crc = crc32fast::Hasher::new();
+        crc.update(kind);
+        crc.update(&data);
+        file.seek(SeekFrom::Current(-8))?;
+        file.write_all(&data)?;
+        file.write_all(&crc.finalize().to_be_bytes())?;
+        return file.flush();
+    }
+}
@@ -0,0 +1,368 @@
+use std::path::{Path, PathBuf};
+
+/// What a new day's parser starts from, by the shape of the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Template {
+    /// A rectangle of characters, parsed into a `Grid`
+    Grid,
+    /// One number per line
+    Lines,
+    /// Groups of lines separated by blank lines, one number per line
+    Blocks,
+}
+
+impl Template {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "grid" => Some(Template::Grid),
+            "lines" => Some(Template::Lines),
+            "blocks" => Some(Template::Blocks),
+            _ => None,
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Template::Grid => GRID,
+            Template::Lines => LINES,
+            Template::Blocks => BLOCKS,
+        }
+    }
+}
+
+/// The mod.rs every unstarted day has, replaced without asking
+const STUB: &str = "\
+use crate::{ParseError, Puzzle};
+
+pub struct %NAME%;
+
+impl Puzzle for %NAME% {
+    const DAY: u32 = %DAY%;
+
+    type Input = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, _contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(())
+    }
+
+    fn part1(&self, _input: &Self::Input) -> usize {
+        0
+    }
+
+    fn part2(&self, _input: &Self::Input) -> usize {
+        0
+    }
+}
+";
+
+const GRID: &str = "\
+use crate::common::Grid;
+use crate::{ParseError, Puzzle};
+
+pub struct %NAME%;
+
+impl Puzzle for %NAME% {
+    const DAY: u32 = %DAY%;
+
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(contents, |_, cell, _| Ok(cell))
+    }
+
+    fn part1(&self, _grid: &Self::Input) -> usize {
+        0
+    }
+
+    fn part2(&self, _grid: &Self::Input) -> usize {
+        0
+    }
+}
+";
+
+const LINES: &str = "\
+use crate::common::error::parse_number;
+use crate::{ParseError, Puzzle};
+
+pub struct %NAME%;
+
+impl Puzzle for %NAME% {
+    const DAY: u32 = %DAY%;
+
+    type Input = Vec<i64>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        contents
+            .lines()
+            .map(|line| parse_number::<i64>(contents, line))
+            .collect()
+    }
+
+    fn part1(&self, _numbers: &Self::Input) -> i64 {
+        0
+    }
+
+    fn part2(&self, _numbers: &Self::Input) -> i64 {
+        0
+    }
+}
+";
+
+const BLOCKS: &str = "\
+use crate::common::error::parse_number;
+use crate::{ParseError, Puzzle};
+
+pub struct %NAME%;
+
+impl Puzzle for %NAME% {
+    const DAY: u32 = %DAY%;
+
+    type Input = Vec<Vec<i64>>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        contents
+            .split(\"\\n\\n\")
+            .map(|block| {
+                block
+                    .lines()
+                    .map(|line| parse_number::<i64>(contents, line))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(&self, _blocks: &Self::Input) -> i64 {
+        0
+    }
+
+    fn part2(&self, _blocks: &Self::Input) -> i64 {
+        0
+    }
+}
+";
+
+const BIN: &str = "\
+use advent_of_code_2022::%MODULE%::%NAME%;
+
+fn main() {
+    advent_of_code_2022::run(&%NAME%);
+}
+";
+
+/// `source` with the placeholders filled in for `day`
+fn fill(source: &str, day: u32) -> String {
+    source
+        .replace("%MODULE%", &format!("day{:02}", day))
+        .replace("%NAME%", &format!("Day{:02}", day))
+        .replace("%DAY%", &day.to_string())
+}
+
+/// What scaffolding did to a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+/// `contents` with `entry` added after the entry of the closest earlier
+/// day, or `None` if `day` has one already. An entry starts with a line
+/// that starts with `key(day)` and ends with the first `end` after it.
+fn insert_entry(
+    contents: &str,
+    day: u32,
+    entry: &str,
+    key: impl Fn(u32) -> String,
+    end: &str,
+) -> Result<Option<String>, String> {
+    let start_of = |day: u32| {
+        let key = key(day);
+        let mut offset = 0;
+        for line in contents.split_inclusive('\n') {
+            if line.trim_start().starts_with(&key) {
+                return Some(offset);
+            }
+            offset += line.len();
+        }
+        None
+    };
+    if start_of(day).is_some() {
+        return Ok(None);
+    }
+
+    let start = (1..day)
+        .rev()
+        .find_map(start_of)
+        .ok_or_else(|| format!("no earlier day to add day {} after", day))?;
+    let at = contents[start..]
+        .find(end)
+        .map(|index| start + index + end.len())
+        .unwrap_or(contents.len());
+    Ok(Some(format!(
+        "{}{}{}",
+        &contents[..at],
+        entry,
+        &contents[at..]
+    )))
+}
+
+/// The `criterion_group!` of benches/days.rs listing every bench function,
+/// wrapped like rustfmt does
+fn criterion_group(contents: &str) -> String {
+    let names = contents
+        .lines()
+        .filter_map(|line| line.strip_prefix("fn "))
+        .filter_map(|line| line.strip_suffix("(c: &mut Criterion) {"))
+        .collect::<Vec<&str>>();
+    let one_line = format!("criterion_group!(benches, {});", names.join(", "));
+    if one_line.len() <= 100 {
+        return one_line + "\n";
+    }
+
+    let mut group = String::from("criterion_group!(\n");
+    let mut line = String::from("    benches,");
+    for name in names {
+        if line.len() + name.len() + 2 > 100 {
+            group.push_str(&line);
+            group.push('\n');
+            line = format!("    {},", name);
+        } else {
+            line.push_str(&format!(" {},", name));
+        }
+    }
+    group.push_str(line.trim_end_matches(','));
+    group.push_str("\n);\n");
+    group
+}
+
+/// Rewrites the file at `path` with `update`, which gives `None` to leave it
+fn update(
+    path: PathBuf,
+    update: impl FnOnce(&str) -> Result<Option<String>, String>,
+) -> Result<(PathBuf, Change), String> {
+    let contents =
+        std::fs::read_to_string(&path).map_err(|error| format!("{}: {}", path.display(), error))?;
+    match update(&contents).map_err(|error| format!("{}: {}", path.display(), error))? {
+        Some(updated) if updated != contents => {
+            std::fs::write(&path, updated)
+                .map_err(|error| format!("{}: {}", path.display(), error))?;
+            Ok((path, Change::Updated))
+        }
+        _ => Ok((path, Change::Unchanged)),
+    }
+}
+
+/// Writes `contents` to `path` unless it holds something other than `stub`
+fn create(path: PathBuf, contents: &str, stub: &str) -> Result<(PathBuf, Change), String> {
+    let change = match std::fs::read_to_string(&path) {
+        Ok(existing) if existing == stub && existing != contents => Change::Updated,
+        Ok(_) => return Ok((path, Change::Unchanged)),
+        Err(_) => Change::Created,
+    };
+    std::fs::write(&path, contents).map_err(|error| format!("{}: {}", path.display(), error))?;
+    Ok((path, change))
+}
+
+/// Sets up `day` in the crate at `root`: its directory with a solver made
+/// from `template` and a binary, and its entries in Cargo.toml, lib.rs,
+/// the day list, the known answers tests and the benchmarks. Whatever is
+/// there already is kept, except for the untouched stub solver.
+pub fn scaffold(
+    root: &Path,
+    day: u32,
+    template: Template,
+) -> Result<Vec<(PathBuf, Change)>, String> {
+    let module = format!("day{:02}", day);
+    let directory = root.join(&module);
+    std::fs::create_dir_all(&directory)
+        .map_err(|error| format!("{}: {}", directory.display(), error))?;
+
+    let changes = vec![
+        create(
+            directory.join("mod.rs"),
+            &fill(template.source(), day),
+            &fill(STUB, day),
+        )?,
+        create(
+            directory.join(format!("{}.rs", module)),
+            &fill(BIN, day),
+            "",
+        )?,
+        create(directory.join("README"), "", "")?,
+        create(directory.join("input.txt"), "", "")?,
+        update(root.join("Cargo.toml"), |contents| {
+            insert_entry(
+                contents,
+                day,
+                &format!("\n[[bin]]\nname = \"{0}\"\npath = \"{0}/{0}.rs\"\n", module),
+                |day| format!("path = \"day{0:02}/day{0:02}.rs\"", day),
+                "\n",
+            )
+        })?,
+        update(root.join("lib.rs"), |contents| {
+            insert_entry(
+                contents,
+                day,
+                &format!("pub mod {};\n", module),
+                |day| format!("pub mod day{:02};", day),
+                "\n",
+            )
+        })?,
+        update(root.join("common").join("days.rs"), |contents| {
+            insert_entry(
+                contents,
+                day,
+                &fill("        %DAY% => Box::new(%MODULE%::%NAME%),\n", day),
+                |day| fill("%DAY% => Box::new(%MODULE%::%NAME%),", day),
+                "\n",
+            )
+        })?,
+        update(root.join("tests").join("answers.rs"), |contents| {
+            insert_entry(
+                contents,
+                day,
+                &format!("    {}: {};\n", module, day),
+                |day| format!("day{:02}:", day),
+                "\n",
+            )
+        })?,
+        update(root.join("benches").join("days.rs"), |contents| {
+            let Some(contents) = insert_entry(
+                contents,
+                day,
+                &fill(
+                    "\nfn %MODULE%(c: &mut Criterion) {\n    bench_day(c, %DAY%, &%MODULE%::%NAME%, &InputKind::ALL);\n}\n",
+                    day,
+                ),
+                |day| format!("fn day{:02}(c: &mut Criterion) {{", day),
+                "\n}\n",
+            )?
+            else {
+                return Ok(None);
+            };
+            let start = contents
+                .find("criterion_group!(")
+                .ok_or("no criterion_group! to add the day to")?;
+            let end = start
+                + contents[start..]
+                    .find(");\n")
+                    .ok_or("the criterion_group! isn't closed")?
+                + 3;
+            Ok(Some(format!(
+                "{}{}{}",
+                &contents[..start],
+                criterion_group(&contents),
+                &contents[end..]
+            )))
+        })?,
+    ];
+    Ok(changes)
+}
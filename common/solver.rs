@@ -0,0 +1,111 @@
+use crate::common::{Answer, ParseError};
+use crate::Puzzle;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u32) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Solution {
+    pub part: Part,
+    pub answer: Answer,
+    /// Time spent solving the part, parsing not included
+    pub elapsed: Duration,
+}
+
+/// The solved parts of one input
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub parse_time: Duration,
+    pub solutions: Vec<Solution>,
+}
+
+/// Object safe view of a `Puzzle`, so days with different input and output
+/// types can be stored and run side by side.
+pub trait Solver {
+    fn day(&self) -> u32;
+
+    /// Parse `contents` once and solve the requested parts
+    fn solve(&self, contents: &str, parts: &[Part]) -> Result<Report, ParseError>;
+}
+
+/// Answer one part of `puzzle`
+pub fn solve_part<P: Puzzle>(puzzle: &P, input: &P::Input, part: Part) -> Answer {
+    match part {
+        Part::One => puzzle.part1(input).into(),
+        Part::Two => puzzle.part2(input).into(),
+    }
+}
+
+/// Time `parse` and then `solve` for each of the `parts`
+pub fn measure<I>(
+    parse: impl FnOnce() -> Result<I, ParseError>,
+    parts: &[Part],
+    mut solve: impl FnMut(&I, Part) -> Answer,
+) -> Result<Report, ParseError> {
+    let start = Instant::now();
+    let input = parse()?;
+    let parse_time = start.elapsed();
+
+    let solutions = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = solve(&input, *part);
+
+            Solution {
+                part: *part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(Report {
+        parse_time,
+        solutions,
+    })
+}
+
+impl<P: Puzzle> Solver for P {
+    fn day(&self) -> u32 {
+        P::DAY
+    }
+
+    fn solve(&self, contents: &str, parts: &[Part]) -> Result<Report, ParseError> {
+        measure(
+            || self.parse(contents),
+            parts,
+            |input, part| solve_part(self, input, part),
+        )
+    }
+}
@@ -0,0 +1,181 @@
+use crate::common::Part;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// How the site judged an answer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint which way
+    Wrong,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+        })
+    }
+}
+
+/// The page the site answers a submission with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Response {
+    Judged(Verdict),
+    /// Answers came in too quickly, the answer wasn't looked at
+    RateLimited(Duration),
+    /// The part was solved before, or the first part isn't solved yet
+    WrongLevel,
+}
+
+/// `4m 29s` in "You have 4m 29s left to wait."
+fn wait_time(page: &str) -> Option<Duration> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    page[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.len().checked_sub(1)?);
+            let number = number.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Response {
+    pub fn parse(page: &str) -> Result<Self, String> {
+        if page.contains("That's the right answer") {
+            Ok(Response::Judged(Verdict::Correct))
+        } else if page.contains("That's not the right answer") {
+            Ok(Response::Judged(
+                if page.contains("your answer is too high") {
+                    Verdict::TooHigh
+                } else if page.contains("your answer is too low") {
+                    Verdict::TooLow
+                } else {
+                    Verdict::Wrong
+                },
+            ))
+        } else if page.contains("You gave an answer too recently") {
+            wait_time(page)
+                .map(Response::RateLimited)
+                .ok_or_else(|| String::from("rate limited without a time to wait"))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Ok(Response::WrongLevel)
+        } else {
+            Err(String::from("the answer page has no verdict on it"))
+        }
+    }
+}
+
+/// One judged answer
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Guess {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted so far, stored as `[[guess]]` tables
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct History {
+    #[serde(default, rename = "guess")]
+    guesses: Vec<Guess>,
+}
+
+pub fn history_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("submissions.toml")
+}
+
+impl History {
+    /// A missing file is an empty history
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => {
+                toml::from_str(&contents).map_err(|error| format!("{}: {}", path.display(), error))
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(History::default()),
+            Err(error) => Err(format!("{}: {}", path.display(), error)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = toml::to_string(self).map_err(|error| error.to_string())?;
+        std::fs::write(path, contents).map_err(|error| format!("{}: {}", path.display(), error))
+    }
+
+    pub fn guesses(&self, day: u32, part: Part) -> impl Iterator<Item = &Guess> {
+        self.guesses
+            .iter()
+            .filter(move |guess| guess.day == day && guess.part == part.number())
+    }
+
+    pub fn record(&mut self, day: u32, part: Part, answer: &str, verdict: Verdict) {
+        self.guesses.push(Guess {
+            day,
+            part: part.number(),
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+
+    /// Highest answer that was too low and lowest one that was too high
+    pub fn bounds(&self, day: u32, part: Part) -> (Option<i64>, Option<i64>) {
+        let numbers = |verdict| {
+            self.guesses(day, part)
+                .filter(move |guess| guess.verdict == verdict)
+                .filter_map(|guess| guess.answer.parse::<i64>().ok())
+        };
+        (
+            numbers(Verdict::TooLow).max(),
+            numbers(Verdict::TooHigh).min(),
+        )
+    }
+
+    /// Why `answer` can't be right according to earlier guesses, if it can't
+    pub fn refuse(&self, day: u32, part: Part, answer: &str) -> Option<String> {
+        if let Some(correct) = self
+            .guesses(day, part)
+            .find(|guess| guess.verdict == Verdict::Correct)
+        {
+            return Some(format!(
+                "day {} part {} is solved already, the answer was {}",
+                day, part, correct.answer
+            ));
+        }
+        if let Some(guess) = self.guesses(day, part).find(|guess| guess.answer == answer) {
+            return Some(format!(
+                "{} was guessed before and was {}",
+                answer, guess.verdict
+            ));
+        }
+
+        let number = answer.parse::<i64>().ok()?;
+        match self.bounds(day, part) {
+            (Some(low), _) if number <= low => Some(format!(
+                "{} is too low, {} was too low already",
+                answer, low
+            )),
+            (_, Some(high)) if number >= high => Some(format!(
+                "{} is too high, {} was too high already",
+                answer, high
+            )),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use image::RgbImage;
+use std::fmt::Write as _;
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Typed on standard input, followed by enter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// `p` pauses and resumes
+    Pause,
+    /// An empty line shows the next frame while paused
+    Step,
+    /// `q` stops drawing, solving carries on
+    Quit,
+}
+
+impl Control {
+    /// The control a line stands for, `None` for anything else
+    pub fn from_line(line: &str) -> Option<Control> {
+        match line.trim() {
+            "" => Some(Control::Step),
+            "p" => Some(Control::Pause),
+            "q" => Some(Control::Quit),
+            _ => None,
+        }
+    }
+}
+
+const HELP: &str = "p+enter: pause/resume  enter: step while paused  q+enter: stop drawing";
+
+/// What to do after a control was handled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reaction {
+    /// Handle the next control, waiting for one while paused
+    Next,
+    /// Show the frame
+    Show,
+    /// Stop drawing
+    Stop,
+}
+
+/// Whether frames are shown as they come or one at a time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Playback {
+    pub paused: bool,
+}
+
+impl Playback {
+    /// Handle `control`, `None` when standard input is closed and nothing can
+    /// resume a pause any more
+    pub fn handle(&mut self, control: Option<Control>) -> Reaction {
+        match control {
+            Some(Control::Pause) => {
+                self.paused = !self.paused;
+                Reaction::Next
+            }
+            Some(Control::Step) if self.paused => Reaction::Show,
+            Some(Control::Step) => Reaction::Next,
+            Some(Control::Quit) => Reaction::Stop,
+            None => {
+                self.paused = false;
+                Reaction::Show
+            }
+        }
+    }
+}
+
+/// A frame as text with 24 bit ANSI colors, two rows of cells per line, the
+/// top one in the foreground of `▀` and the bottom one in its background.
+/// The `first` frame clears the screen and hides the cursor, later ones draw
+/// over the last.
+pub fn ansi_frame(image: &RgbImage, frame: u32, playback: Playback, first: bool) -> String {
+    let mut screen = String::new();
+    if first {
+        screen.push_str("\x1b[2J\x1b[?25l");
+    }
+    screen.push_str("\x1b[H");
+    for y in (0..image.height()).step_by(2) {
+        // colors are only sent when they change, which keeps SSH fast
+        let mut colors = None;
+        for x in 0..image.width() {
+            let top = image.get_pixel(x, y).0;
+            let bottom = if y + 1 < image.height() {
+                image.get_pixel(x, y + 1).0
+            } else {
+                top
+            };
+            if colors != Some((top, bottom)) {
+                let _ = write!(
+                    screen,
+                    "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m",
+                    top[0], top[1], top[2], bottom[0], bottom[1], bottom[2]
+                );
+                colors = Some((top, bottom));
+            }
+            screen.push('\u{2580}');
+        }
+        screen.push_str("\x1b[0m\x1b[K\n");
+    }
+    let state = if playback.paused { "paused" } else { "playing" };
+    let _ = writeln!(screen, "frame {} {}  {}\x1b[K", frame, state, HELP);
+    screen
+}
+
+/// Draws frames in place on standard error with 24 bit ANSI colors, two rows
+/// of cells per line of text, so the answers on standard output stay apart.
+pub(crate) struct Terminal {
+    delay: Duration,
+    shown: Option<Instant>,
+    /// Started with the first frame, standard input may hold the puzzle input
+    /// until then
+    controls: Option<Receiver<Control>>,
+    playback: Playback,
+}
+
+impl Terminal {
+    pub(crate) fn new(delay: Duration) -> Self {
+        Terminal {
+            delay,
+            shown: None,
+            controls: None,
+            playback: Playback::default(),
+        }
+    }
+
+    /// Show one frame, returns false once drawing was stopped
+    pub(crate) fn show(&mut self, image: &RgbImage, frame: u32) -> io::Result<bool> {
+        let controls = self.controls.get_or_insert_with(read_controls);
+
+        // handle what was typed since the last frame, then wait while paused
+        loop {
+            let control = if self.playback.paused {
+                controls.recv().ok()
+            } else {
+                match controls.try_recv() {
+                    Ok(control) => Some(control),
+                    Err(TryRecvError::Empty) => break,
+                    Err(TryRecvError::Disconnected) => None,
+                }
+            };
+            match self.playback.handle(control) {
+                Reaction::Next => {}
+                Reaction::Show => break,
+                Reaction::Stop => return Ok(false),
+            }
+        }
+
+        if let Some(shown) = self.shown {
+            thread::sleep(self.delay.saturating_sub(shown.elapsed()));
+        }
+
+        let screen = ansi_frame(image, frame, self.playback, self.shown.is_none());
+        let mut stderr = io::stderr().lock();
+        stderr.write_all(screen.as_bytes())?;
+        stderr.flush()?;
+        self.shown = Some(Instant::now());
+        Ok(true)
+    }
+
+    /// Give the cursor back
+    pub(crate) fn finish(&mut self) -> io::Result<()> {
+        if self.shown.is_some() {
+            let mut stderr = io::stderr().lock();
+            stderr.write_all(b"\x1b[0m\x1b[?25h")?;
+            stderr.flush()?;
+        }
+        Ok(())
+    }
+}
+
+fn read_controls() -> Receiver<Control> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let control = match line {
+                Ok(line) => match Control::from_line(&line) {
+                    Some(control) => control,
+                    None => continue,
+                },
+                Err(_) => break,
+            };
+            if sender.send(control).is_err() {
+                break;
+            }
+        }
+    });
+    receiver
+}
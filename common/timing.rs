@@ -0,0 +1,161 @@
+use crate::common::days::{self, InputKind};
+use crate::common::{input_name, read_input, Part, Report};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// One day of a timing run
+#[derive(Debug, Clone)]
+pub struct Timing {
+    pub day: u32,
+    pub input: String,
+    /// The solved parts, or why the day couldn't be solved
+    pub outcome: Result<Report, String>,
+}
+
+impl Timing {
+    /// Parse and solve times together, zero for a failed day
+    pub fn elapsed(&self) -> Duration {
+        match &self.outcome {
+            Ok(report) => {
+                report.parse_time
+                    + report
+                        .solutions
+                        .iter()
+                        .map(|solution| solution.elapsed)
+                        .sum::<Duration>()
+            }
+            Err(_) => Duration::ZERO,
+        }
+    }
+}
+
+/// Every day of a timing run, slowest first and failures before them
+#[derive(Debug, Clone)]
+pub struct TimingReport {
+    pub timings: Vec<Timing>,
+    /// Days without the input or with an empty one, which aren't started yet
+    pub skipped: Vec<u32>,
+    /// From the start of the first day to the end of the last one
+    pub wall_time: Duration,
+    pub threads: usize,
+}
+
+impl TimingReport {
+    /// The sum of every day's time, what the budget is for
+    pub fn total(&self) -> Duration {
+        self.timings.iter().map(Timing::elapsed).sum()
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &Timing> {
+        self.timings.iter().filter(|timing| timing.outcome.is_err())
+    }
+}
+
+/// What a panic was raised with, when it was a message
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => String::from("panicked"),
+        },
+    }
+}
+
+/// Solve `parts` of `day` on its `kind` of input, `None` if there's none
+fn time_day(day: u32, kind: InputKind, parts: &[Part]) -> Option<Timing> {
+    let path = days::input_path(day, kind).display().to_string();
+    let input = input_name(&path).to_string();
+    let contents = match read_input(&path) {
+        Ok(contents) if contents.is_empty() => return None,
+        Ok(contents) => contents,
+        Err(reason) if reason.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(reason) => {
+            return Some(Timing {
+                day,
+                outcome: Err(format!("{}: {}", input, reason)),
+                input,
+            })
+        }
+    };
+
+    let puzzle = days::puzzle_for(day, kind)?;
+    let outcome = panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve(&contents, parts)))
+        .map_err(|payload| format!("panicked: {}", panic_message(payload)))
+        .and_then(|report| report.map_err(|reason| reason.with_file(&input).to_string()));
+    Some(Timing {
+        day,
+        input,
+        outcome,
+    })
+}
+
+/// Solve `days` side by side on `threads` threads, or one per core for
+/// `None`. Failing days, like a malformed input or a panicking solver, are
+/// kept in the report instead of stopping the others.
+pub fn time_days(
+    days: &[u32],
+    kind: InputKind,
+    parts: &[Part],
+    threads: Option<usize>,
+) -> Result<TimingReport, String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads.unwrap_or(0))
+        .build()
+        .map_err(|error| error.to_string())?;
+
+    let start = Instant::now();
+    let timed = pool.install(|| {
+        days.par_iter()
+            .map(|day| (*day, time_day(*day, kind, parts)))
+            .collect::<Vec<(u32, Option<Timing>)>>()
+    });
+    let wall_time = start.elapsed();
+
+    let skipped = timed
+        .iter()
+        .filter(|(_, timing)| timing.is_none())
+        .map(|(day, _)| *day)
+        .collect();
+    let mut timings = timed
+        .into_iter()
+        .filter_map(|(_, timing)| timing)
+        .collect::<Vec<Timing>>();
+    timings.sort_by_key(|timing| (timing.outcome.is_ok(), std::cmp::Reverse(timing.elapsed())));
+    Ok(TimingReport {
+        timings,
+        skipped,
+        wall_time,
+        threads: pool.current_num_threads(),
+    })
+}
+
+/// How long all days together may take, stored in budget.toml
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Budget {
+    pub total_ms: u64,
+}
+
+pub fn budget_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("budget.toml")
+}
+
+impl Budget {
+    /// `None` when there's no budget file
+    pub fn load(path: &Path) -> Result<Option<Self>, String> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents)
+                .map(Some)
+                .map_err(|error| format!("{}: {}", path.display(), error)),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(format!("{}: {}", path.display(), error)),
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        Duration::from_millis(self.total_ms)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::common::error::parse_number;
+use crate::common::Generate;
+use crate::{ParseError, Puzzle};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+pub struct Day01;
+
+impl Puzzle for Day01 {
+    const DAY: u32 = 1;
+
+    /// Total calories carried by each elf
+    type Input = Vec<usize>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        contents
+            .split("\n\n")
+            .map(|lines| {
+                lines
+                    .lines()
+                    .map(|x| parse_number::<usize>(contents, x))
+                    .sum()
+            })
+            .collect()
+    }
+
+    fn part1(&self, elves: &Self::Input) -> usize {
+        *elves.iter().max().unwrap()
+    }
+
+    fn part2(&self, elves: &Self::Input) -> usize {
+        elves.iter().sorted().rev().take(3).sum()
+    }
+}
+
+impl Generate for Day01 {
+    /// `size` elves carrying one to fifteen items each
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                (0..rng.gen_range(1, 16))
+                    .map(|_| rng.gen_range(1000, 70000).to_string())
+                    .join("\n")
+            })
+            .join("\n\n")
+            + "\n"
+    }
+}
@@ -0,0 +1,90 @@
+use crate::common::Generate;
+use crate::{ParseError, Puzzle};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+pub struct Day02;
+
+/// Opponent column and own column of the strategy guide
+type Round = (char, char);
+
+impl Puzzle for Day02 {
+    const DAY: u32 = 2;
+
+    type Input = Vec<Round>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        contents
+            .lines()
+            .map(|line| {
+                let (a, b) = line
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::new(contents, line, "a round like `A Y`"))?;
+                let column = |token: &str, choices: [char; 3]| -> Result<char, ParseError> {
+                    match token.chars().next() {
+                        Some(choice) if token.len() == 1 && choices.contains(&choice) => Ok(choice),
+                        _ => Err(ParseError::new(
+                            contents,
+                            token,
+                            format!("one of {}, {} or {}", choices[0], choices[1], choices[2]),
+                        )),
+                    }
+                };
+                Ok((column(a, ['A', 'B', 'C'])?, column(b, ['X', 'Y', 'Z'])?))
+            })
+            .collect()
+    }
+
+    fn part1(&self, rounds: &Self::Input) -> usize {
+        rounds
+            .iter()
+            .map(|round| match round {
+                ('A', 'X') => 1 + 3,
+                ('A', 'Y') => 2 + 6,
+                ('A', 'Z') => 3,
+                ('B', 'X') => 1,
+                ('B', 'Y') => 2 + 3,
+                ('B', 'Z') => 3 + 6,
+                ('C', 'X') => 1 + 6,
+                ('C', 'Y') => 2,
+                ('C', 'Z') => 3 + 3,
+                _ => unreachable!(),
+            })
+            .sum()
+    }
+
+    fn part2(&self, rounds: &Self::Input) -> usize {
+        rounds
+            .iter()
+            .map(|round| match round {
+                ('A', 'X') => 3,
+                ('A', 'Y') => 1 + 3,
+                ('A', 'Z') => 2 + 6,
+                ('B', 'X') => 1,
+                ('B', 'Y') => 2 + 3,
+                ('B', 'Z') => 3 + 6,
+                ('C', 'X') => 2,
+                ('C', 'Y') => 3 + 3,
+                ('C', 'Z') => 1 + 6,
+                _ => unreachable!(),
+            })
+            .sum()
+    }
+}
+
+impl Generate for Day02 {
+    /// `size` rounds
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    ['A', 'B', 'C'].choose(rng).unwrap(),
+                    ['X', 'Y', 'Z'].choose(rng).unwrap()
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,159 @@
+use crate::common::Generate;
+use crate::{ParseError, Puzzle};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+pub struct Day03;
+
+fn to_priority(item: char) -> u32 {
+    if item.is_lowercase() {
+        1 + (item as u32) - ('a' as u32)
+    } else {
+        27 + (item as u32) - ('A' as u32)
+    }
+}
+
+impl Puzzle for Day03 {
+    const DAY: u32 = 3;
+
+    /// One rucksack per line
+    type Input = Vec<String>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let rucksacks = contents
+            .lines()
+            .map(|line| {
+                if let Some((index, _)) = line
+                    .char_indices()
+                    .find(|(_, item)| !item.is_ascii_alphabetic())
+                {
+                    return Err(ParseError::new(
+                        contents,
+                        &line[index..index + 1],
+                        "an item between a-z or A-Z",
+                    ));
+                }
+                if line.len() % 2 != 0 {
+                    return Err(ParseError::new(
+                        contents,
+                        line,
+                        "two compartments with the same number of items",
+                    ));
+                }
+                Ok(line)
+            })
+            .collect::<Result<Vec<&str>, ParseError>>()?;
+
+        if rucksacks.len() % 3 != 0 {
+            return Err(ParseError::new(
+                contents,
+                &contents[contents.len()..],
+                "groups of three rucksacks",
+            ));
+        }
+        for group in rucksacks.chunks(3) {
+            if !group[2]
+                .chars()
+                .any(|item| group[0].contains(item) && group[1].contains(item))
+            {
+                return Err(ParseError::new(
+                    contents,
+                    group[2],
+                    "a badge, an item in all three rucksacks of the group",
+                ));
+            }
+        }
+        Ok(rucksacks.into_iter().map(String::from).collect())
+    }
+
+    fn part1(&self, rucksacks: &Self::Input) -> usize {
+        rucksacks
+            .iter()
+            .filter_map(|line| {
+                let line_size = line.len();
+                let (a, b) = line.split_at(line_size / 2);
+
+                a.chars().find(|a_| b.contains(*a_))
+            })
+            .fold(0, |acc, item| acc + to_priority(item) as usize)
+    }
+
+    fn part2(&self, rucksacks: &Self::Input) -> usize {
+        rucksacks
+            .iter()
+            .map(|line| line.chars().sorted().unique().collect::<Vec<char>>())
+            .collect::<Vec<Vec<char>>>()
+            .chunks(3)
+            .map(|group| {
+                // the parser made sure every group has a badge
+                group
+                    .concat()
+                    .into_iter()
+                    .sorted()
+                    .dedup_with_count()
+                    .find_map(|(count, badge)| {
+                        if count == 3 {
+                            Some(to_priority(badge))
+                        } else {
+                            None
+                        }
+                    })
+                    .unwrap() as usize
+            })
+            .sum()
+    }
+}
+
+/// Items of one compartment: `shared` and `required` plus random picks from
+/// `items` up to `count`
+fn compartment(
+    rng: &mut StdRng,
+    count: usize,
+    shared: char,
+    required: Option<char>,
+    items: &[char],
+) -> Vec<char> {
+    let mut compartment = vec![shared];
+    compartment.extend(required);
+    while compartment.len() < count {
+        compartment.push(*items.choose(rng).unwrap());
+    }
+    compartment.shuffle(rng);
+    compartment
+}
+
+impl Generate for Day03 {
+    /// `size` groups of three elves. Every rucksack has exactly one item in
+    /// both compartments and every group exactly one badge.
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        let all_items = ('a'..='z').chain('A'..='Z').collect::<Vec<char>>();
+        let mut rucksacks = String::new();
+
+        for _ in 0..size.max(1) {
+            let mut items = all_items.clone();
+            items.shuffle(rng);
+            let badge = items.pop().unwrap();
+
+            // each elf gets items none of the others have
+            for own_items in items.chunks(items.len() / 3).take(3) {
+                let mut own_items = own_items.to_vec();
+                own_items.push(badge);
+                own_items.shuffle(rng);
+                let shared = own_items.pop().unwrap();
+                let (left, right) = own_items.split_at(own_items.len() / 2);
+
+                let required = |side: &[char]| Some(badge).filter(|badge| side.contains(badge));
+                let count = rng.gen_range(2, 17);
+                let left = compartment(rng, count, shared, required(left), left);
+                let right = compartment(rng, count, shared, required(right), right);
+                rucksacks.extend(left.into_iter().chain(right));
+                rucksacks.push('\n');
+            }
+        }
+        rucksacks
+    }
+}
@@ -0,0 +1,69 @@
+use crate::common::error::{parse_number, split_once};
+use crate::common::Generate;
+use crate::{ParseError, Puzzle};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+pub struct Day04;
+
+/// First and last section id of an assignment
+type Assignment = (usize, usize);
+
+impl Puzzle for Day04 {
+    const DAY: u32 = 4;
+
+    type Input = Vec<(Assignment, Assignment)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let assignment = |group: &str| -> Result<Assignment, ParseError> {
+            let (start, end) = split_once(contents, group, "-", "a section range like `2-4`")?;
+            let (first, last) = (parse_number(contents, start)?, parse_number(contents, end)?);
+            if first > last {
+                return Err(ParseError::new(
+                    contents,
+                    group,
+                    "a range from a lower to a higher section",
+                ));
+            }
+            Ok((first, last))
+        };
+
+        contents
+            .lines()
+            .map(|line| {
+                let (a, b) = split_once(contents, line, ",", "a pair like `2-4,6-8`")?;
+                Ok((assignment(a)?, assignment(b)?))
+            })
+            .collect()
+    }
+
+    fn part1(&self, pairs: &Self::Input) -> usize {
+        pairs
+            .iter()
+            .filter(|(a, b)| (a.0 >= b.0 && a.1 <= b.1) || (b.0 >= a.0 && b.1 <= a.1))
+            .count()
+    }
+
+    fn part2(&self, pairs: &Self::Input) -> usize {
+        pairs
+            .iter()
+            .filter(|(a, b)| a.0 <= b.1 && b.0 <= a.1)
+            .count()
+    }
+}
+
+impl Generate for Day04 {
+    /// `size` pairs of section ranges between 1 and 99
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        let mut range = || {
+            let start = rng.gen_range(1, 100);
+            let end = rng.gen_range(start, 100);
+            format!("{}-{}", start, end)
+        };
+        (0..size.max(1))
+            .map(|_| format!("{},{}\n", range(), range()))
+            .collect()
+    }
+}
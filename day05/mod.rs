@@ -0,0 +1,273 @@
+use crate::common::canvas::{Canvas, Palette, WHITE};
+use crate::common::error::parse_number;
+use crate::common::{BoundingBox, Generate, Part, Point, Recorder, Visualize};
+use crate::{Answer, ParseError, Puzzle};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+use regex::Regex;
+use std::collections::VecDeque;
+
+pub struct Day05;
+
+pub type Stacks = Vec<VecDeque<char>>;
+
+/// Amount, source stack and destination stack, zero indexed
+pub type Move = (usize, usize, usize);
+
+const CRATE: [[usize; 8]; 8] = [
+    [1, 1, 1, 1, 1, 1, 1, 1],
+    [1, 2, 2, 2, 2, 2, 2, 1],
+    [1, 2, 2, 2, 2, 2, 2, 1],
+    [1, 2, 2, 2, 2, 2, 2, 1],
+    [1, 2, 2, 2, 2, 2, 2, 1],
+    [1, 2, 2, 2, 2, 2, 2, 1],
+    [1, 2, 2, 2, 2, 2, 2, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1],
+];
+
+/// `height` is the tallest a stack can get, so every frame has the same size
+fn draw_stacks(stacks: &Stacks, height: usize, recorder: &mut Recorder) {
+    if !recorder.step() {
+        return;
+    }
+
+    let height = height as i32;
+    let margin = 1;
+    let block_size = CRATE.len() as i32 + margin;
+
+    let palette = Palette::new(&[WHITE, [197, 203, 23], [210, 220, 12]]);
+    let mut canvas = Canvas::new(4).border(2).bounds(BoundingBox::new(
+        Point::ORIGIN,
+        Point::new(
+            block_size * stacks.len() as i32 + margin - 1,
+            block_size * height + margin - 1,
+        ),
+    ));
+
+    for (stack_index, stack) in stacks.iter().enumerate() {
+        for level in 0..stack.len() as i32 {
+            let corner = Point::new(
+                block_size * stack_index as i32 + margin,
+                block_size * (height - 1 - level) + margin,
+            );
+            canvas.blit(corner, &CRATE, &palette);
+        }
+    }
+
+    recorder.record(&canvas);
+}
+
+/// Run the procedure, moving crates one at a time or `all_at_once`, and
+/// return the crates on top
+fn rearrange(
+    (stacks, moves): &(Stacks, Vec<Move>),
+    all_at_once: bool,
+    recorder: &mut Recorder,
+) -> String {
+    let mut stacks = stacks.clone();
+    let height = stacks.iter().map(|stack| stack.len()).sum();
+    draw_stacks(&stacks, height, recorder);
+
+    for &(amount, source, destination) in moves {
+        if all_at_once {
+            let stack_size = stacks[source].len();
+            let cargo = stacks[source]
+                .drain(stack_size - amount..)
+                .collect::<VecDeque<char>>();
+            stacks[destination].extend(cargo);
+            draw_stacks(&stacks, height, recorder);
+        } else {
+            for _ in 0..amount {
+                let cargo = stacks[source].pop_back().unwrap();
+                stacks[destination].push_back(cargo);
+                draw_stacks(&stacks, height, recorder);
+            }
+        }
+    }
+
+    top_crates(&stacks)
+}
+
+pub fn print_stacks(stacks: &Stacks) {
+    let max_height = stacks.iter().map(|stack| stack.len()).max().unwrap();
+    for depth in 0..max_height {
+        for stack in stacks {
+            let index = max_height - depth;
+            if index > stack.len() {
+                print!("    ");
+            } else {
+                print!("[{}] ", stack[index - 1]);
+            }
+        }
+        println!();
+    }
+}
+
+fn top_crates(stacks: &Stacks) -> String {
+    stacks
+        .iter()
+        .map(|stack| stack[stack.len() - 1])
+        .collect::<String>()
+}
+
+impl Puzzle for Day05 {
+    const DAY: u32 = 5;
+
+    type Input = (Stacks, Vec<Move>);
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let re = Regex::new(r"(\[|\s)(?P<crate>([A-Z]|\s))(\]|\s)\s?").unwrap();
+        let (drawing, procedure) = contents.split_once("\n\n").ok_or_else(|| {
+            ParseError::new(
+                contents,
+                &contents[contents.len()..],
+                "a blank line between the drawing and the moves",
+            )
+        })?;
+
+        let mut stacks: Stacks = Vec::new();
+        drawing.lines().for_each(|line| {
+            re.captures_iter(line)
+                .enumerate()
+                .for_each(|(index, cargo)| {
+                    if stacks.len() < (index + 1) {
+                        stacks.push(VecDeque::new());
+                    }
+                    let crate_id = cargo["crate"].chars().next().unwrap();
+                    if crate_id != ' ' {
+                        stacks[index].push_front(crate_id);
+                    }
+                });
+        });
+
+        let instruction_pattern =
+            Regex::new(r"^move\s(?P<amount>\d+)\sfrom\s(?P<source>\d+)\sto\s(?P<destination>\d+)$")
+                .unwrap();
+        let stack_index = |text: &str| -> Result<usize, ParseError> {
+            match parse_number::<usize>(contents, text)? {
+                stack if (1..=stacks.len()).contains(&stack) => Ok(stack - 1),
+                _ => Err(ParseError::new(
+                    contents,
+                    text,
+                    format!("a stack between 1 and {}", stacks.len()),
+                )),
+            }
+        };
+        // crates on each stack as the moves are made, no move can take more
+        let mut heights = stacks
+            .iter()
+            .map(|stack| stack.len())
+            .collect::<Vec<usize>>();
+        let moves = procedure
+            .lines()
+            .map(|line| {
+                let parsed = instruction_pattern.captures(line).ok_or_else(|| {
+                    ParseError::new(contents, line, "a move like `move 1 from 2 to 1`")
+                })?;
+
+                let amount_text = parsed.name("amount").unwrap().as_str();
+                let amount = parse_number::<usize>(contents, amount_text)?;
+                let source = stack_index(parsed.name("source").unwrap().as_str())?;
+                let destination = stack_index(parsed.name("destination").unwrap().as_str())?;
+                if amount > heights[source] {
+                    return Err(ParseError::new(
+                        contents,
+                        amount_text,
+                        format!(
+                            "at most the {} crates on stack {}",
+                            heights[source],
+                            source + 1
+                        ),
+                    ));
+                }
+                heights[source] -= amount;
+                heights[destination] += amount;
+                Ok((amount, source, destination))
+            })
+            .collect::<Result<Vec<Move>, ParseError>>()?;
+
+        if let Some(empty) = heights.iter().position(|height| *height == 0) {
+            return Err(ParseError::new(
+                contents,
+                &contents[contents.len()..],
+                format!("moves that leave a crate on top of stack {}", empty + 1),
+            ));
+        }
+
+        Ok((stacks, moves))
+    }
+
+    fn part1(&self, input: &Self::Input) -> String {
+        rearrange(input, false, &mut Recorder::off())
+    }
+
+    fn part2(&self, input: &Self::Input) -> String {
+        rearrange(input, true, &mut Recorder::off())
+    }
+}
+
+impl Visualize for Day05 {
+    fn visualize(&self, input: &Self::Input, part: Part, recorder: &mut Recorder) -> Answer {
+        rearrange(input, part == Part::Two, recorder).into()
+    }
+}
+
+impl Generate for Day05 {
+    /// Two to nine stacks and `size` moves, no stack ever ends up empty
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        let mut stacks = (0..rng.gen_range(2, 10))
+            .map(|_| {
+                (0..rng.gen_range(2, 9))
+                    .map(|_| rng.gen_range(b'A', b'Z' + 1) as char)
+                    .collect::<Vec<char>>()
+            })
+            .collect::<Vec<Vec<char>>>();
+
+        let height = stacks.iter().map(Vec::len).max().unwrap();
+        let mut drawing = (0..height)
+            .rev()
+            .map(|level| {
+                stacks
+                    .iter()
+                    .map(|stack| match stack.get(level) {
+                        Some(cargo) => format!("[{}]", cargo),
+                        None => String::from("   "),
+                    })
+                    .join(" ")
+            })
+            .collect::<Vec<String>>();
+        drawing.push(
+            (1..=stacks.len())
+                .map(|number| format!(" {} ", number))
+                .join(" "),
+        );
+
+        // there are more crates than stacks, so some stack can always give
+        let mut moves = Vec::new();
+        for _ in 0..size.max(1) {
+            let source = loop {
+                let source = rng.gen_range(0, stacks.len());
+                if stacks[source].len() > 1 {
+                    break source;
+                }
+            };
+            let destination = (source + rng.gen_range(1, stacks.len())) % stacks.len();
+            let amount = rng.gen_range(1, stacks[source].len());
+
+            let keep = stacks[source].len() - amount;
+            let cargo = stacks[source].split_off(keep);
+            stacks[destination].extend(cargo);
+            moves.push(format!(
+                "move {} from {} to {}",
+                amount,
+                source + 1,
+                destination + 1
+            ));
+        }
+
+        format!("{}\n\n{}\n", drawing.join("\n"), moves.join("\n"))
+    }
+}
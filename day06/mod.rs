@@ -0,0 +1,86 @@
+use crate::common::Generate;
+use crate::{ParseError, Puzzle};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+pub struct Day06;
+
+/// Position after the first four different characters in a row
+fn packet_marker(signal: &[char]) -> Option<usize> {
+    signal
+        .iter()
+        .enumerate()
+        .tuple_windows()
+        .find_map(|((_, a), (_, b), (_, c), (count, d))| {
+            if [a, b, c, d].iter().all_unique() {
+                return Some(count + 1);
+            }
+            None
+        })
+}
+
+impl Puzzle for Day06 {
+    const DAY: u32 = 6;
+
+    /// The datastream buffer
+    type Input = Vec<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let line = match contents.lines().next() {
+            Some(line) if !line.is_empty() => line,
+            _ => return Err(ParseError::new(contents, &contents[..0], "a datastream")),
+        };
+        let signal = line.chars().collect::<Vec<char>>();
+        if packet_marker(&signal).is_none() {
+            return Err(ParseError::new(
+                contents,
+                line,
+                "a start-of-packet marker, four different characters in a row",
+            ));
+        }
+        Ok(signal)
+    }
+
+    fn part1(&self, signal: &Self::Input) -> usize {
+        // the parser made sure there is one
+        packet_marker(signal).unwrap()
+    }
+
+    fn part2(&self, signal: &Self::Input) -> usize {
+        let line_length = signal.len();
+        for start in 0..line_length {
+            let end = start + 14;
+            if end > line_length {
+                return 0;
+            }
+            let all_unique = signal[start..end].iter().all_unique();
+            if all_unique {
+                return end;
+            }
+        }
+        0
+    }
+}
+
+impl Generate for Day06 {
+    /// A datastream of `size` characters, at least 14. The message marker
+    /// comes after a stretch with too few distinct characters to hold one.
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        let length = size.max(14);
+        let mut letters = ('a'..='z').collect::<Vec<char>>();
+        letters.shuffle(rng);
+
+        let alphabet = &letters[..rng.gen_range(4, 14)];
+        let before = rng.gen_range(0, length - 14 + 1);
+        let mut stream = (0..before)
+            .map(|_| *alphabet.choose(rng).unwrap())
+            .collect::<String>();
+        stream.extend(letters.choose_multiple(rng, 14));
+        stream.extend((stream.len()..length).map(|_| *letters.choose(rng).unwrap()));
+        stream + "\n"
+    }
+}
@@ -0,0 +1,223 @@
+use crate::common::error::{parse_number, split_once};
+use crate::common::generate::lowercase_name;
+use crate::common::Generate;
+use crate::{ParseError, Puzzle};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+pub struct Day07;
+
+/// Every file and directory by full path, directories have size 0
+pub type Filesystem = Vec<(String, usize)>;
+
+const ROOT: &str = "/";
+
+const DISK_SPACE: usize = 70000000;
+const NEEDED_SPACE: usize = 30000000;
+
+pub fn get_directory_size(filesystem_flat: &Filesystem, start_path: &str) -> usize {
+    filesystem_flat
+        .iter()
+        .filter(|(path, _)| {
+            if path != start_path {
+                let path_tokens = path.split('/').collect::<Vec<&str>>();
+                let path_tokens_length = path_tokens.len();
+                let path_part = path_tokens[..path_tokens_length - 1].join("/");
+
+                path_part == start_path
+            } else {
+                false
+            }
+        })
+        .map(|(path, size)| {
+            if *size == 0 {
+                get_directory_size(filesystem_flat, path)
+            } else {
+                *size
+            }
+        })
+        .sum()
+}
+
+impl Puzzle for Day07 {
+    const DAY: u32 = 7;
+
+    type Input = Filesystem;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let mut filesystem_flat: Filesystem = Vec::new();
+        let mut current_path: Vec<&str> = Vec::new();
+        let mut used_space: usize = 0;
+
+        filesystem_flat.push((String::from(ROOT), 0));
+
+        for line in contents.lines() {
+            if line.starts_with('$') {
+                if let Some(directory) = line.strip_prefix("$ cd ") {
+                    if directory == ".." {
+                        current_path.pop();
+                    } else {
+                        current_path.push(directory);
+                    }
+                } else if line != "$ ls" {
+                    return Err(ParseError::new(
+                        contents,
+                        line,
+                        "a command like `$ cd <directory>` or `$ ls`",
+                    ));
+                }
+            } else if let Some(directory) = line.strip_prefix("dir ") {
+                current_path.push(directory);
+                filesystem_flat.push((current_path.join("/"), 0));
+                current_path.pop();
+            } else {
+                let (filesize_str, filename) = split_once(
+                    contents,
+                    line,
+                    " ",
+                    "a listing like `dir <name>` or `<size> <name>`",
+                )?;
+                let filesize = parse_number::<usize>(contents, filesize_str)?;
+                used_space = used_space
+                    .checked_add(filesize)
+                    .filter(|used_space| *used_space <= DISK_SPACE)
+                    .ok_or_else(|| {
+                        ParseError::new(
+                            contents,
+                            filesize_str,
+                            format!("files that fit on a disk of {}", DISK_SPACE),
+                        )
+                    })?;
+                current_path.push(filename);
+                filesystem_flat.push((current_path.join("/"), filesize));
+                current_path.pop();
+            }
+        }
+
+        Ok(filesystem_flat)
+    }
+
+    fn part1(&self, filesystem_flat: &Self::Input) -> usize {
+        filesystem_flat
+            .iter()
+            .filter_map(|(path, size)| {
+                if *size == 0 {
+                    let folder_size = get_directory_size(filesystem_flat, path);
+                    if folder_size < 100000 {
+                        return Some(folder_size);
+                    }
+                }
+                None
+            })
+            .sum()
+    }
+
+    fn part2(&self, filesystem_flat: &Self::Input) -> usize {
+        let used_space = get_directory_size(filesystem_flat, ROOT);
+        // the parser made sure the files fit on the disk
+        let current_space = DISK_SPACE - used_space;
+
+        filesystem_flat
+            .iter()
+            .filter_map(|(path, size)| {
+                if *size == 0 {
+                    let folder_size = get_directory_size(filesystem_flat, path);
+                    if (current_space + folder_size) > NEEDED_SPACE {
+                        return Some(folder_size);
+                    }
+                }
+                None
+            })
+            .min()
+            // deleting everything frees enough
+            .unwrap_or(used_space)
+    }
+}
+
+/// A directory of the generated filesystem
+#[derive(Default)]
+struct Directory {
+    files: Vec<(String, usize)>,
+    directories: Vec<(String, Directory)>,
+}
+
+impl Directory {
+    fn has(&self, name: &str) -> bool {
+        self.files.iter().any(|(file, _)| file == name)
+            || self
+                .directories
+                .iter()
+                .any(|(directory, _)| directory == name)
+    }
+
+    /// The directory reached by taking the `choice`th subdirectory at every level
+    fn descend(&mut self, choices: &[usize]) -> &mut Directory {
+        match choices.split_first() {
+            Some((choice, rest)) => self.directories[*choice].1.descend(rest),
+            None => self,
+        }
+    }
+
+    /// `$ ls` and its listing, then the same for every subdirectory
+    fn transcript(&self, lines: &mut Vec<String>) {
+        lines.push(String::from("$ ls"));
+        lines.extend(
+            self.directories
+                .iter()
+                .map(|(name, _)| format!("dir {}", name)),
+        );
+        lines.extend(
+            self.files
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        );
+        for (name, directory) in &self.directories {
+            lines.push(format!("$ cd {}", name));
+            directory.transcript(lines);
+            lines.push(String::from("$ cd .."));
+        }
+    }
+}
+
+impl Generate for Day07 {
+    /// The terminal output of browsing a filesystem with `size` files and
+    /// directories, small enough to leave room for the update
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        let entries = size.max(1);
+        let largest_file = (40_000_000 / entries).clamp(1, 300_000);
+        let mut root = Directory::default();
+        let mut paths: Vec<Vec<usize>> = vec![vec![]];
+
+        for _ in 0..entries {
+            let path = paths[rng.gen_range(0, paths.len())].clone();
+            let parent = root.descend(&path);
+            let is_directory = rng.gen_bool(0.3);
+            let name = loop {
+                let mut name = lowercase_name(rng, 1..=8);
+                if !is_directory && rng.gen_bool(0.5) {
+                    name = format!("{}.{}", name, lowercase_name(rng, 3..=3));
+                }
+                if !parent.has(&name) {
+                    break name;
+                }
+            };
+
+            if is_directory {
+                let mut child = path;
+                child.push(parent.directories.len());
+                parent.directories.push((name, Directory::default()));
+                paths.push(child);
+            } else {
+                parent
+                    .files
+                    .push((name, rng.gen_range(1, largest_file + 1)));
+            }
+        }
+
+        let mut lines = vec![String::from("$ cd /")];
+        root.transcript(&mut lines);
+        lines.join("\n") + "\n"
+    }
+}
@@ -0,0 +1,95 @@
+use crate::common::{Direction, Generate, Grid, Point};
+use crate::{ParseError, Puzzle};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+pub struct Day08;
+
+pub type TreeHeights = Grid<usize>;
+
+/// Whether every tree between `position` and one of the edges is lower
+pub fn is_visible(tree_heights: &TreeHeights, position: Point) -> bool {
+    let current_height = tree_heights[position];
+
+    Direction::ALL.iter().any(|direction| {
+        tree_heights
+            .ray(position, direction.step())
+            .all(|(_, height)| *height < current_height)
+    })
+}
+
+/// Product of the viewing distances, each up to the first tree that is at
+/// least as high or up to the edge
+pub fn scenic_score(tree_heights: &TreeHeights, position: Point) -> usize {
+    let current_height = tree_heights[position];
+
+    Direction::ALL
+        .iter()
+        .map(|direction| {
+            let mut trees = 0;
+            for (_, height) in tree_heights.ray(position, direction.step()) {
+                trees += 1;
+                if *height >= current_height {
+                    break;
+                }
+            }
+            trees
+        })
+        .product()
+}
+
+impl Puzzle for Day08 {
+    const DAY: u32 = 8;
+
+    type Input = TreeHeights;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let tree_heights = Grid::parse(contents, |_, height, text| {
+            height
+                .to_digit(10)
+                .map(|height| height as usize)
+                .ok_or_else(|| ParseError::new(contents, text, "a tree height between 0 and 9"))
+        })?;
+        if tree_heights.width() == 0 {
+            return Err(ParseError::new(
+                contents,
+                &contents[..0],
+                "at least one row of tree heights",
+            ));
+        }
+        Ok(tree_heights)
+    }
+
+    fn part1(&self, tree_heights: &Self::Input) -> usize {
+        tree_heights
+            .positions()
+            .filter(|position| is_visible(tree_heights, *position))
+            .count()
+    }
+
+    fn part2(&self, tree_heights: &Self::Input) -> usize {
+        tree_heights
+            .positions()
+            .map(|position| scenic_score(tree_heights, position))
+            .max()
+            // the parser made sure there is a tree
+            .unwrap()
+    }
+}
+
+impl Generate for Day08 {
+    /// A forest of `size` × `size` trees
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        let side = size.max(1);
+        (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| char::from_digit(rng.gen_range(0, 10), 10).unwrap())
+                    .chain(Some('\n'))
+                    .collect::<String>()
+            })
+            .collect()
+    }
+}
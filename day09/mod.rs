@@ -0,0 +1,156 @@
+use crate::common::canvas::{Canvas, Palette};
+use crate::common::error::{parse_number, split_once};
+use crate::common::{
+    BoundingBox, Direction, Generate, Part, Point, Recorder, SparseGrid, Visualize,
+};
+use crate::{Answer, ParseError, Puzzle};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+pub struct Day09;
+
+/// Number of times the tail visited each position
+pub type Space = SparseGrid<i32>;
+
+/// Every knot stays within the box the head moves through
+fn head_bounds(motions: &[(Direction, i32)]) -> BoundingBox {
+    let heads = motions
+        .iter()
+        .scan(Point::ORIGIN, |head, &(direction, amount)| {
+            *head += direction.step() * amount;
+            Some(*head)
+        });
+    heads.fold(
+        BoundingBox::new(Point::ORIGIN, Point::ORIGIN),
+        BoundingBox::including,
+    )
+}
+
+fn draw_map(map: &Space, rope: &[Point], bounds: BoundingBox, recorder: &mut Recorder) {
+    if !recorder.step() {
+        return;
+    }
+
+    // rope head first, then the tail trail getting lighter the more it was visited
+    let palette = Palette::new(&[
+        [219, 109, 80],
+        [166, 145, 80],
+        [177, 157, 94],
+        [186, 168, 111],
+        [194, 178, 128],
+        [202, 188, 145],
+        [211, 199, 162],
+        [219, 209, 180],
+    ]);
+    let mut canvas = Canvas::new(8).border(2).min_size(32, 32).bounds(bounds);
+
+    for (pos, visited_count) in map.iter() {
+        canvas.fill(pos, palette.color(*visited_count as usize));
+    }
+    for pos in rope.iter() {
+        canvas.fill(*pos, palette.color(0));
+    }
+
+    recorder.record(&canvas);
+}
+
+fn pull_rope(
+    space: &mut Space,
+    rope: &mut [Point],
+    direction: Direction,
+    amount: i32,
+    bounds: BoundingBox,
+    recorder: &mut Recorder,
+) {
+    for _ in 0..amount {
+        rope[0] += direction.step();
+        for index in 1..rope.len() {
+            let previous = rope[index - 1];
+            // each knot follows the one ahead of it once they stop touching
+            if !rope[index].is_adjacent(previous) {
+                rope[index] = rope[index].step_toward(previous);
+            }
+        }
+
+        space
+            .entry(*rope.last().unwrap())
+            .and_modify(|e| *e += 1)
+            .or_insert(1);
+
+        draw_map(space, rope, bounds, recorder);
+    }
+}
+
+/// Number of positions the tail of a rope with `knots` knots visits
+fn simulate(motions: &[(Direction, i32)], knots: usize, recorder: &mut Recorder) -> usize {
+    let mut space: Space = Space::new();
+    let mut rope = vec![Point::ORIGIN; knots];
+    let bounds = head_bounds(motions);
+
+    draw_map(&space, &rope, bounds, recorder);
+    for &(direction, amount) in motions {
+        pull_rope(&mut space, &mut rope, direction, amount, bounds, recorder);
+    }
+
+    space.len()
+}
+
+impl Puzzle for Day09 {
+    const DAY: u32 = 9;
+
+    /// Head motions as a direction and a number of steps
+    type Input = Vec<(Direction, i32)>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        contents
+            .lines()
+            .map(|line| {
+                let (direction_str, amount_str) =
+                    split_once(contents, line, " ", "a motion like `R 4`")?;
+                let amount = i32::try_from(parse_number::<u32>(contents, amount_str)?)
+                    .map_err(|_| ParseError::new(contents, amount_str, "a smaller number"))?;
+
+                let direction = Direction::from_letter(direction_str).ok_or_else(|| {
+                    ParseError::new(contents, direction_str, "one of L, R, U or D")
+                })?;
+                Ok((direction, amount))
+            })
+            .collect()
+    }
+
+    fn part1(&self, motions: &Self::Input) -> usize {
+        simulate(motions, 2, &mut Recorder::off())
+    }
+
+    fn part2(&self, motions: &Self::Input) -> usize {
+        simulate(motions, 10, &mut Recorder::off())
+    }
+}
+
+impl Visualize for Day09 {
+    fn visualize(&self, motions: &Self::Input, part: Part, recorder: &mut Recorder) -> Answer {
+        let knots = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+        simulate(motions, knots, recorder).into()
+    }
+}
+
+impl Generate for Day09 {
+    /// `size` head motions of one to twenty steps
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    ["U", "R", "D", "L"].choose(rng).unwrap(),
+                    rng.gen_range(1, 21)
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,141 @@
+use crate::common::error::parse_number;
+use crate::common::Generate;
+use crate::{Answer, ParseError, Puzzle};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+pub struct Day10;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    AddX,
+}
+
+pub type DecodedInstruction = ((Instruction, usize), Option<i64>);
+
+pub struct Cpu<'a> {
+    instructions: &'a [DecodedInstruction],
+    x: i64,
+    pc: usize,
+    delay: usize,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(instructions: &'a [DecodedInstruction], start_value: i64) -> Self {
+        Self {
+            instructions,
+            x: start_value,
+            pc: 0,
+            delay: 0,
+        }
+    }
+
+    pub fn value(&self) -> i64 {
+        self.x
+    }
+
+    pub fn tick(&mut self) {
+        // the register keeps its value once the program has ended
+        let Some(&((instruction, cycle_count), value_opt)) = self.instructions.get(self.pc) else {
+            return;
+        };
+        if self.delay == cycle_count {
+            match instruction {
+                Instruction::Noop => {}
+                Instruction::AddX => self.x += value_opt.unwrap(),
+            }
+            self.delay = 1;
+            self.pc += 1;
+        } else {
+            self.delay += 1;
+        }
+    }
+}
+
+impl Puzzle for Day10 {
+    const DAY: u32 = 10;
+
+    type Input = Vec<DecodedInstruction>;
+    type Output1 = i64;
+    /// The letters drawn on the CRT
+    type Output2 = Answer;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        contents
+            .lines()
+            .map(|line| match line.split_once(' ') {
+                None if line == "noop" => Ok(((Instruction::Noop, 1), None)),
+                Some(("addx", value)) => Ok((
+                    (Instruction::AddX, 2),
+                    Some(parse_number::<i64>(contents, value)?),
+                )),
+                _ => Err(ParseError::new(
+                    contents,
+                    line,
+                    "an instruction like `noop` or `addx <value>`",
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(&self, program: &Self::Input) -> i64 {
+        let mut cpu = Cpu::new(program, 1);
+
+        let mut result = 0;
+        let thresholds = [20, 60, 100, 140, 180, 220];
+        for tick in 0..221 {
+            if thresholds.contains(&tick) {
+                result += tick * cpu.value();
+            }
+
+            cpu.tick();
+        }
+        result
+    }
+
+    fn part2(&self, program: &Self::Input) -> Answer {
+        let mut cpu = Cpu::new(program, 1);
+        let mut pixels: Vec<char> = Vec::new();
+        let width = 40;
+        let height = 6;
+        for tick in 0..(width * height) {
+            cpu.tick();
+            let xpos = cpu.value();
+            let pixel_pos = tick % width;
+            let sprite = [xpos - 1, xpos, xpos + 1];
+
+            pixels.push(if sprite.contains(&pixel_pos) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+
+        Answer::Image(
+            pixels
+                .chunks(width as usize)
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<String>>()
+                .join("\n"),
+        )
+    }
+}
+
+impl Generate for Day10 {
+    /// `size` instructions that keep the sprite on the screen
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        let mut x = 1;
+        (0..size.max(1))
+            .map(|_| {
+                let value = rng.gen_range(-10, 11);
+                if value == 0 || !(0..40).contains(&(x + value)) {
+                    String::from("noop\n")
+                } else {
+                    x += value;
+                    format!("addx {}\n", value)
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,326 @@
+use crate::common::error::{parse_number, split_once};
+use crate::common::Generate;
+use crate::{ParseError, Puzzle};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+pub struct Day11;
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    inspect_count: usize,
+    items: Vec<i64>,
+    operation: Operation,
+    second_operand: Option<i64>,
+    divisible_by: i64,
+    throw_to_if_true: usize,
+    throw_to_if_false: usize,
+}
+
+fn parse_operation(contents: &str, value: &str) -> Result<(Operation, Option<i64>), ParseError> {
+    let expression = value
+        .strip_prefix("new = old ")
+        .ok_or_else(|| ParseError::new(contents, value, "an operation like `new = old * 19`"))?;
+    let (operator, operand) = split_once(
+        contents,
+        expression,
+        " ",
+        "an operator followed by an operand",
+    )?;
+
+    let operation = match operator {
+        "+" => Operation::Add,
+        "*" => Operation::Multiply,
+        _ => return Err(ParseError::new(contents, operator, "`+` or `*`")),
+    };
+    let second_operand = match operand {
+        "old" => None,
+        _ => Some(parse_number::<i64>(contents, operand)?),
+    };
+    Ok((operation, second_operand))
+}
+
+/// The text after `prefix` in `value`
+fn strip<'a>(contents: &str, value: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    value
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(contents, value, format!("`{}<number>`", prefix)))
+}
+
+pub fn parse_monkeys(contents: &str) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    let mut throw_targets: Vec<&str> = Vec::new();
+
+    for section in contents.split("\n\n") {
+        let mut items: Vec<i64> = Vec::new();
+        let mut operation: Option<(Operation, Option<i64>)> = None;
+        let mut divisible_by: Option<i64> = None;
+        let mut throw_to_if_true: Option<usize> = None;
+        let mut throw_to_if_false: Option<usize> = None;
+
+        for line in section.lines() {
+            if line.starts_with("Monkey") {
+                continue;
+            }
+            let (key, value) = split_once(
+                contents,
+                line,
+                ": ",
+                "a line like `  Test: divisible by 13`",
+            )?;
+
+            match key {
+                "  Starting items" => {
+                    items = value
+                        .split(", ")
+                        .map(|x| parse_number::<i64>(contents, x))
+                        .collect::<Result<Vec<i64>, ParseError>>()?;
+                }
+                "  Operation" => operation = Some(parse_operation(contents, value)?),
+                "  Test" => {
+                    let divisor = strip(contents, value, "divisible by ")?;
+                    divisible_by = match parse_number::<i64>(contents, divisor)? {
+                        0 => return Err(ParseError::new(contents, divisor, "a non-zero divisor")),
+                        divisor => Some(divisor),
+                    };
+                }
+                "    If true" => {
+                    let target = strip(contents, value, "throw to monkey ")?;
+                    throw_to_if_true = Some(parse_number::<usize>(contents, target)?);
+                    throw_targets.push(target);
+                }
+                "    If false" => {
+                    let target = strip(contents, value, "throw to monkey ")?;
+                    throw_to_if_false = Some(parse_number::<usize>(contents, target)?);
+                    throw_targets.push(target);
+                }
+                _ => {
+                    return Err(ParseError::new(
+                        contents,
+                        key,
+                        "one of `Starting items`, `Operation`, `Test`, `If true` or `If false`",
+                    ))
+                }
+            }
+        }
+
+        let missing = |key: &str| {
+            let header = section.lines().next().unwrap_or(section);
+            ParseError::new(contents, header, format!("a monkey with an `{}` line", key))
+        };
+        let (operation, second_operand) = operation.ok_or_else(|| missing("Operation"))?;
+
+        monkeys.push(Monkey {
+            inspect_count: 0,
+            items,
+            operation,
+            second_operand,
+            divisible_by: divisible_by.ok_or_else(|| missing("Test"))?,
+            throw_to_if_true: throw_to_if_true.ok_or_else(|| missing("If true"))?,
+            throw_to_if_false: throw_to_if_false.ok_or_else(|| missing("If false"))?,
+        });
+    }
+
+    for target in throw_targets {
+        if target.parse::<usize>().unwrap() >= monkeys.len() {
+            return Err(ParseError::new(
+                contents,
+                target,
+                format!("a monkey between 0 and {}", monkeys.len() - 1),
+            ));
+        }
+    }
+
+    Ok(monkeys)
+}
+
+/// Play the given number of rounds, `relief` is applied to every new worry level
+fn play_rounds(monkeys: &mut [Monkey], rounds: usize, relief: impl Fn(i64) -> i64) {
+    for _ in 0..rounds {
+        for monkey_id in 0..monkeys.len() {
+            let monkey = &monkeys[monkey_id];
+            let throws = monkey
+                .items
+                .iter()
+                .map(|worry_level| {
+                    let new_worry_level = relief(match monkey.operation {
+                        Operation::Add => {
+                            worry_level + monkey.second_operand.unwrap_or(*worry_level)
+                        }
+                        Operation::Multiply => {
+                            worry_level * monkey.second_operand.unwrap_or(*worry_level)
+                        }
+                    });
+
+                    if (new_worry_level % monkey.divisible_by) == 0 {
+                        (monkey.throw_to_if_true, new_worry_level)
+                    } else {
+                        (monkey.throw_to_if_false, new_worry_level)
+                    }
+                })
+                .collect::<Vec<(usize, i64)>>();
+
+            for (throw_id, worry_level) in throws {
+                monkeys[monkey_id].inspect_count += 1;
+                monkeys[throw_id].items.push(worry_level);
+            }
+
+            monkeys[monkey_id].items.clear();
+        }
+    }
+}
+
+fn monkey_business(monkeys: &[Monkey]) -> usize {
+    monkeys
+        .iter()
+        .sorted_by_key(|monkey| monkey.inspect_count)
+        .rev()
+        .take(2)
+        .fold(1, |acc, monkey| acc * monkey.inspect_count)
+}
+
+impl Puzzle for Day11 {
+    const DAY: u32 = 11;
+
+    type Input = Vec<Monkey>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        parse_monkeys(contents)
+    }
+
+    fn part1(&self, monkeys: &Self::Input) -> usize {
+        let mut monkeys = monkeys.clone();
+
+        play_rounds(&mut monkeys, 20, |worry_level| worry_level / 3);
+
+        monkey_business(&monkeys)
+    }
+
+    fn part2(&self, monkeys: &Self::Input) -> usize {
+        let mut monkeys = monkeys.clone();
+
+        let common_denominator = monkeys
+            .iter()
+            .fold(1, |acc, monkey| acc * monkey.divisible_by);
+
+        play_rounds(&mut monkeys, 10000, |worry_level| {
+            worry_level % common_denominator
+        });
+
+        monkey_business(&monkeys)
+    }
+}
+
+/// Whether the worry levels of part one fit, without the modulo of part two
+/// nothing keeps them from growing
+fn fits_part1(monkeys: &[Monkey]) -> bool {
+    let mut items = monkeys
+        .iter()
+        .map(|monkey| monkey.items.clone())
+        .collect::<Vec<Vec<i64>>>();
+
+    for _ in 0..20 {
+        for (monkey_id, monkey) in monkeys.iter().enumerate() {
+            for worry_level in std::mem::take(&mut items[monkey_id]) {
+                let operand = monkey.second_operand.unwrap_or(worry_level);
+                let new_worry_level = match monkey.operation {
+                    Operation::Add => worry_level.checked_add(operand),
+                    Operation::Multiply => worry_level.checked_mul(operand),
+                };
+                let Some(new_worry_level) = new_worry_level.map(|level| level / 3) else {
+                    return false;
+                };
+                let target = if new_worry_level % monkey.divisible_by == 0 {
+                    monkey.throw_to_if_true
+                } else {
+                    monkey.throw_to_if_false
+                };
+                items[target].push(new_worry_level);
+            }
+        }
+    }
+    true
+}
+
+fn random_monkeys(rng: &mut StdRng, count: usize) -> Vec<Monkey> {
+    // distinct primes keep the product of part two small enough to square
+    let mut divisors = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    divisors.shuffle(rng);
+
+    (0..count)
+        .map(|monkey_id| {
+            let (operation, second_operand) = match rng.gen_range(0, 5) {
+                0 => (Operation::Multiply, None),
+                1 | 2 => (Operation::Multiply, Some(rng.gen_range(2, 20))),
+                _ => (Operation::Add, Some(rng.gen_range(1, 9))),
+            };
+            let mut targets = (0..count)
+                .filter(|target| *target != monkey_id)
+                .collect::<Vec<usize>>();
+            targets.shuffle(rng);
+            // with two monkeys both throws go to the other one
+            let throw_to_if_false = *targets.get(1).unwrap_or(&targets[0]);
+
+            Monkey {
+                inspect_count: 0,
+                items: (0..rng.gen_range(1, 9))
+                    .map(|_| rng.gen_range(50, 100))
+                    .collect(),
+                operation,
+                second_operand,
+                divisible_by: divisors[monkey_id],
+                throw_to_if_true: targets[0],
+                throw_to_if_false,
+            }
+        })
+        .collect()
+}
+
+impl Generate for Day11 {
+    /// `size` monkeys, between two and nine, which only throw to each other
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        let count = size.clamp(2, 9);
+        let monkeys = loop {
+            let monkeys = random_monkeys(rng, count);
+            if fits_part1(&monkeys) {
+                break monkeys;
+            }
+        };
+
+        monkeys
+            .iter()
+            .enumerate()
+            .map(|(monkey_id, monkey)| {
+                let operator = match monkey.operation {
+                    Operation::Add => '+',
+                    Operation::Multiply => '*',
+                };
+                let operand = monkey
+                    .second_operand
+                    .map_or(String::from("old"), |operand| operand.to_string());
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  \
+                     Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                     If false: throw to monkey {}\n",
+                    monkey_id,
+                    monkey.items.iter().join(", "),
+                    operator,
+                    operand,
+                    monkey.divisible_by,
+                    monkey.throw_to_if_true,
+                    monkey.throw_to_if_false
+                )
+            })
+            .join("\n")
+    }
+}
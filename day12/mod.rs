@@ -0,0 +1,206 @@
+use crate::common::canvas::{Canvas, Color, Palette};
+use crate::common::{Generate, Grid, Part, Point, Recorder, Visualize};
+use crate::{Answer, ParseError, Puzzle};
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::VecDeque;
+
+pub struct Day12;
+
+pub type HeightMap = Grid<usize>;
+
+pub struct Hill {
+    pub map: HeightMap,
+    pub start: Point,
+    pub end: Point,
+}
+
+/// Heights from dark to light
+fn terrain() -> Palette {
+    Palette::gradient([120, 100, 50], [245, 225, 175], 26)
+}
+
+const PATH: Color = [219, 109, 80];
+
+fn draw_map(map: &HeightMap, came_from: &Grid<Option<Point>>, end: Point, recorder: &mut Recorder) {
+    if !recorder.step() {
+        return;
+    }
+
+    let palette = terrain();
+    let mut canvas = Canvas::new(8).border(2).min_size(32, 32);
+
+    for (pos, height) in map.iter() {
+        canvas.fill(pos, palette.color(*height));
+    }
+    let mut pos = Some(end);
+    while let Some(step) = pos {
+        canvas.fill(step, PATH);
+        pos = came_from[step];
+    }
+
+    recorder.record(&canvas);
+}
+
+/// Breadth first search from `start` over the steps `can_step` allows between
+/// heights, the fewest steps to a square where `done` holds
+fn fewest_steps(
+    map: &HeightMap,
+    start: Point,
+    can_step: impl Fn(usize, usize) -> bool,
+    done: impl Fn(Point) -> bool,
+    recorder: &mut Recorder,
+) -> Option<usize> {
+    let mut came_from = Grid::new(map.width(), map.height(), None);
+    let mut visited = Grid::new(map.width(), map.height(), false);
+    visited[start] = true;
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((position, steps)) = queue.pop_front() {
+        draw_map(map, &came_from, position, recorder);
+
+        if done(position) {
+            return Some(steps);
+        }
+        for neighbour in map.neighbours4(position) {
+            if !visited[neighbour] && can_step(map[position], map[neighbour]) {
+                visited[neighbour] = true;
+                came_from[neighbour] = Some(position);
+                queue.push_back((neighbour, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Fewest steps from `start` to `end`, climbing at most one higher each step
+pub fn get_shortest_path(
+    map: &HeightMap,
+    start: Point,
+    end: Point,
+    recorder: &mut Recorder,
+) -> Option<usize> {
+    fewest_steps(
+        map,
+        start,
+        |from, to| to <= from + 1,
+        |pos| pos == end,
+        recorder,
+    )
+}
+
+/// Fewest steps from any lowest square to `end`, walking down from `end`
+pub fn get_shortest_path_from_lowest(
+    map: &HeightMap,
+    end: Point,
+    recorder: &mut Recorder,
+) -> Option<usize> {
+    fewest_steps(
+        map,
+        end,
+        |from, to| from <= to + 1,
+        |pos| map[pos] == 0,
+        recorder,
+    )
+}
+
+/// Fewest steps to the top from the start, or from any lowest square for part two
+fn climb(hill: &Hill, part: Part, recorder: &mut Recorder) -> usize {
+    match part {
+        // the parser made sure E can be reached
+        Part::One => get_shortest_path(&hill.map, hill.start, hill.end, recorder).unwrap(),
+        Part::Two => get_shortest_path_from_lowest(&hill.map, hill.end, recorder).unwrap(),
+    }
+}
+
+impl Puzzle for Day12 {
+    const DAY: u32 = 12;
+
+    type Input = Hill;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let mut start: Option<Point> = None;
+        let mut end: Option<Point> = None;
+
+        let map = Grid::parse(contents, |pos, height, text| match height {
+            'S' => {
+                start = Some(pos);
+                Ok(0)
+            }
+            'E' => {
+                end = Some(pos);
+                Ok(('z' as usize) - ('a' as usize))
+            }
+            'a'..='z' => Ok((height as usize) - ('a' as usize)),
+            _ => Err(ParseError::new(
+                contents,
+                text,
+                "a height between a and z, S or E",
+            )),
+        })?;
+
+        let missing = |what: &str| ParseError::new(contents, &contents[contents.len()..], what);
+        let hill = Hill {
+            map,
+            start: start.ok_or_else(|| missing("a start position marked S"))?,
+            end: end.ok_or_else(|| missing("a best signal position marked E"))?,
+        };
+
+        // S is one of the lowest squares, so part two can reach E as well
+        if get_shortest_path(&hill.map, hill.start, hill.end, &mut Recorder::off()).is_none() {
+            let end = contents.find('E').unwrap();
+            return Err(ParseError::new(
+                contents,
+                &contents[end..end + 1],
+                "a best signal position that can be climbed to from S",
+            ));
+        }
+        Ok(hill)
+    }
+
+    fn part1(&self, hill: &Self::Input) -> usize {
+        climb(hill, Part::One, &mut Recorder::off())
+    }
+
+    fn part2(&self, hill: &Self::Input) -> usize {
+        climb(hill, Part::Two, &mut Recorder::off())
+    }
+}
+
+impl Visualize for Day12 {
+    fn visualize(&self, hill: &Self::Input, part: Part, recorder: &mut Recorder) -> Answer {
+        climb(hill, part, recorder).into()
+    }
+}
+
+impl Generate for Day12 {
+    /// A `size` × `size` map, at least 6 × 6, with random heights around a
+    /// climbable path that winds row by row from the start to the top
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        let side = size.max(6);
+        let mut map = Grid::from_fn(side, side, |_| rng.gen_range(b'a', b'z' + 1) as char);
+
+        let winding = (0..side as i32).flat_map(|y| {
+            (0..side as i32).map(move |x| {
+                let x = if y % 2 == 0 { x } else { side as i32 - 1 - x };
+                Point::new(x, y)
+            })
+        });
+        // long enough to go up one letter at a time
+        let length = rng.gen_range(26, side * side + 1);
+        for (step, position) in winding.take(length).enumerate() {
+            map[position] = match step {
+                0 => 'S',
+                _ if step == length - 1 => 'E',
+                _ => (b'a' + (step * 25 / (length - 1)) as u8) as char,
+            };
+        }
+
+        (0..side)
+            .map(|y| map.row(y).iter().chain(&['\n']).collect::<String>())
+            .collect()
+    }
+}
@@ -0,0 +1,282 @@
+use crate::common::error::parse_number;
+use crate::common::Generate;
+use crate::{ParseError, Puzzle};
+use itertools::EitherOrBoth::{Both, Left, Right};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::cmp::Ordering;
+
+pub struct Day13;
+
+#[derive(Debug)]
+pub struct ParsedToken {
+    pub value: Option<usize>,
+    pub list: Vec<ParsedToken>,
+}
+
+/// Parse the tokens of `packet`, a slice of `contents`, up to the end of the
+/// current list and return the number of characters consumed.
+pub fn parse_tokens(
+    contents: &str,
+    parsed_tokens: &mut Vec<ParsedToken>,
+    packet: &str,
+) -> Result<usize, ParseError> {
+    let mut offset = 0;
+    let mut start_new_token_offset = 0;
+    // whether the current element has a number or list, and whether a comma
+    // came before it, `[]` is fine but `[1,,2]` and `[1,]` aren't
+    let mut element = false;
+    let mut comma = false;
+    let empty_element =
+        |offset: usize| ParseError::new(contents, &packet[offset..offset + 1], "a number or list");
+    while offset < packet.len() {
+        if let Some(token) = packet.chars().nth(offset) {
+            if token == '[' {
+                let start_offset = offset + 1;
+                let mut parsed_list: Vec<ParsedToken> = Vec::new();
+                offset += parse_tokens(contents, &mut parsed_list, &packet[start_offset..])?;
+
+                parsed_tokens.push(ParsedToken {
+                    value: None,
+                    list: parsed_list,
+                });
+                start_new_token_offset = offset;
+                element = true;
+            } else if token == ']' {
+                if comma && !element {
+                    return Err(empty_element(offset));
+                }
+                if start_new_token_offset != offset {
+                    parsed_tokens.push(ParsedToken {
+                        value: Some(parse_number(
+                            contents,
+                            &packet[start_new_token_offset..offset],
+                        )?),
+                        list: vec![],
+                    });
+                }
+                // list end
+                return Ok(offset + 2);
+            } else if token == ',' {
+                if !element {
+                    return Err(empty_element(offset));
+                }
+                if start_new_token_offset != offset {
+                    parsed_tokens.push(ParsedToken {
+                        value: Some(parse_number(
+                            contents,
+                            &packet[start_new_token_offset..offset],
+                        )?),
+                        list: vec![],
+                    });
+                }
+                // separator
+                offset += 1;
+                start_new_token_offset = offset;
+                element = false;
+                comma = true;
+            } else if token.is_ascii_digit() {
+                // neither are list
+                offset += 1;
+                element = true;
+            } else {
+                return Err(ParseError::new(
+                    contents,
+                    &packet[offset..offset + token.len_utf8()],
+                    "a digit, `,`, `[` or `]`",
+                ));
+            }
+        }
+    }
+    Ok(offset)
+}
+
+/// A packet is a list with balanced brackets
+fn check_brackets(contents: &str, packet: &str) -> Result<(), ParseError> {
+    if !packet.starts_with('[') {
+        return Err(ParseError::new(
+            contents,
+            packet,
+            "a packet like `[1,[2,3]]`",
+        ));
+    }
+
+    let mut depth = 0;
+    for (index, token) in packet.char_indices() {
+        match token {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            _ => {}
+        }
+        if depth == 0 && index + 1 < packet.len() {
+            return Err(ParseError::new(
+                contents,
+                &packet[index + 1..],
+                "the end of the packet",
+            ));
+        }
+    }
+    if depth != 0 {
+        return Err(ParseError::new(
+            contents,
+            &packet[packet.len()..],
+            "a closing `]`",
+        ));
+    }
+    Ok(())
+}
+
+/// A single value compared to a list is treated as a list holding only that value
+fn promote(value: usize) -> ParsedToken {
+    ParsedToken {
+        value: None,
+        list: vec![ParsedToken {
+            value: Some(value),
+            list: vec![],
+        }],
+    }
+}
+
+pub fn compare(lhs: &ParsedToken, rhs: &ParsedToken) -> Ordering {
+    match (lhs.value, rhs.value) {
+        (None, None) => {
+            // both are list
+            lhs.list
+                .iter()
+                .zip_longest(rhs.list.iter())
+                .fold(Ordering::Equal, |acc, items| {
+                    if acc != Ordering::Equal {
+                        return acc;
+                    }
+                    match items {
+                        Both(lhs_item, rhs_item) => compare(lhs_item, rhs_item),
+                        Right(_) => Ordering::Less,
+                        Left(_) => Ordering::Greater,
+                    }
+                })
+        }
+        (Some(value), None) => compare(&promote(value), rhs),
+        (None, Some(value)) => compare(lhs, &promote(value)),
+        // both have value
+        (Some(lhs_value), Some(rhs_value)) => lhs_value.cmp(&rhs_value),
+    }
+}
+
+impl Puzzle for Day13 {
+    const DAY: u32 = 13;
+
+    /// Every packet in order, pairs are consecutive packets
+    type Input = Vec<ParsedToken>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    /// Pairs have to be in one order or the other, packets that are the same
+    /// can't be put in order
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let lines = contents
+            .lines()
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<&str>>();
+        let mut packets: Vec<ParsedToken> = Vec::new();
+        for packet in &lines {
+            check_brackets(contents, packet)?;
+            parse_tokens(contents, &mut packets, packet)?;
+        }
+
+        if lines.len() % 2 != 0 {
+            return Err(ParseError::new(
+                contents,
+                &contents[contents.len()..],
+                "a second packet to pair with the last one",
+            ));
+        }
+        for (pair, lines) in packets.chunks(2).zip(lines.chunks(2)) {
+            if compare(&pair[0], &pair[1]) == Ordering::Equal {
+                return Err(ParseError::new(
+                    contents,
+                    lines[1],
+                    "a packet that differs from the one before it",
+                ));
+            }
+        }
+        Ok(packets)
+    }
+
+    fn part1(&self, packets: &Self::Input) -> usize {
+        packets
+            .chunks(2)
+            .enumerate()
+            .filter_map(|(pair_index, pair)| match compare(&pair[0], &pair[1]) {
+                Ordering::Less => Some(pair_index + 1),
+                Ordering::Greater => None,
+                Ordering::Equal => unreachable!("the parser rejects equal pairs"),
+            })
+            .sum()
+    }
+
+    fn part2(&self, packets: &Self::Input) -> usize {
+        let mut divider_packets: Vec<ParsedToken> = Vec::new();
+        for packet in ["[[2]]", "[[6]]"] {
+            parse_tokens(packet, &mut divider_packets, packet).unwrap();
+        }
+
+        let mut packets = packets.iter().collect::<Vec<&ParsedToken>>();
+        packets.sort_by(|a, b| compare(a, b));
+        packets
+            .iter()
+            .enumerate()
+            .filter_map(|(index, packet)| {
+                divider_packets.iter().find_map(|divider_packet| {
+                    match compare(packet, divider_packet) {
+                        Ordering::Less => None,
+                        Ordering::Greater => None,
+                        Ordering::Equal => Some(index + 1),
+                    }
+                })
+            })
+            .product()
+    }
+}
+
+/// A list nested at most `depth` more levels
+fn random_list(rng: &mut StdRng, depth: u32) -> ParsedToken {
+    let list = (0..rng.gen_range(0, 6))
+        .map(|_| {
+            if depth > 0 && rng.gen_bool(0.3) {
+                random_list(rng, depth - 1)
+            } else {
+                ParsedToken {
+                    value: Some(rng.gen_range(0, 11)),
+                    list: vec![],
+                }
+            }
+        })
+        .collect();
+    ParsedToken { value: None, list }
+}
+
+fn format_packet(token: &ParsedToken) -> String {
+    match token.value {
+        Some(value) => value.to_string(),
+        None => format!("[{}]", token.list.iter().map(format_packet).join(",")),
+    }
+}
+
+impl Generate for Day13 {
+    /// `size` pairs of packets, no pair is equal
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        (0..size.max(1))
+            .map(|_| {
+                let left = random_list(rng, 4);
+                let right = loop {
+                    let right = random_list(rng, 4);
+                    if compare(&left, &right) != Ordering::Equal {
+                        break right;
+                    }
+                };
+                format!("{}\n{}\n", format_packet(&left), format_packet(&right))
+            })
+            .join("\n")
+    }
+}
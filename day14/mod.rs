@@ -0,0 +1,257 @@
+use crate::common::canvas::{Canvas, Palette};
+use crate::common::error::{parse_number, split_once};
+use crate::common::{BoundingBox, Direction8, Generate, Grid, Part, Point, Recorder, Visualize};
+use crate::{Answer, ParseError, Puzzle};
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::Rng;
+
+pub struct Day14;
+
+type BoulderSequence = Vec<Point>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+/// The scanned slice of the cavity, with room for the sand to pile up to the
+/// source on the floor two rows below the lowest rock
+#[derive(Debug, Clone)]
+pub struct Cavity {
+    tiles: Grid<Tile>,
+    /// x of the first column of `tiles`
+    left: i32,
+    /// y of the lowest rock
+    pub bottom: i32,
+}
+
+impl Cavity {
+    /// Everything outside the scan is air
+    pub fn get(&self, position: Point) -> Tile {
+        self.tiles
+            .get(position - Point::new(self.left, 0))
+            .copied()
+            .unwrap_or(Tile::Air)
+    }
+
+    fn set(&mut self, position: Point, tile: Tile) {
+        self.tiles[position - Point::new(self.left, 0)] = tile;
+    }
+
+    fn bounds(&self) -> BoundingBox {
+        BoundingBox::new(
+            Point::new(self.left, 0),
+            Point::new(
+                self.left + self.tiles.width() as i32 - 1,
+                self.tiles.height() as i32 - 1,
+            ),
+        )
+    }
+}
+
+const SOURCE: Point = Point::new(500, 0);
+
+/// Rock and sand ids, from dark to light
+fn terrain() -> Palette {
+    Palette::gradient([120, 100, 50], [245, 225, 175], 26)
+}
+
+fn draw_map(cavity: &Cavity, sand_drop: Point, recorder: &mut Recorder) {
+    if !recorder.step() {
+        return;
+    }
+
+    let palette = terrain();
+    let mut canvas = Canvas::new(4)
+        .border(2)
+        .min_size(16, 16)
+        .bounds(cavity.bounds());
+
+    for (position, tile) in cavity.tiles.iter() {
+        let id = match tile {
+            Tile::Air => continue,
+            Tile::Rock => 1,
+            Tile::Sand => 10,
+        };
+        canvas.fill(position + Point::new(cavity.left, 0), palette.color(id));
+    }
+    canvas.fill(sand_drop, palette.color(15));
+
+    recorder.record(&canvas);
+}
+
+fn drop_sand(cavity: &Cavity, y_max: i32, recorder: &mut Recorder) -> Point {
+    let mut sand = SOURCE;
+    while sand.y < y_max {
+        let below = sand + Direction8::Down.step();
+        let diagonal_left = sand + Direction8::DownLeft.step();
+        let diagonal_right = sand + Direction8::DownRight.step();
+
+        if cavity.get(below) == Tile::Air {
+            // not blocked
+            sand = below;
+        } else if cavity.get(diagonal_left) == Tile::Air {
+            sand = diagonal_left;
+        } else if cavity.get(diagonal_right) == Tile::Air {
+            sand = diagonal_right;
+        } else {
+            // blocked
+            return sand;
+        }
+
+        draw_map(cavity, sand, recorder);
+    }
+    sand
+}
+
+/// Units of sand that come to rest, until sand falls into the abyss for part
+/// one or until the source is blocked for part two, where the cavity has a floor
+fn pour_sand(cavity: &Cavity, part: Part, recorder: &mut Recorder) -> usize {
+    let mut cavity = cavity.clone();
+    let mut resting = 0;
+
+    match part {
+        // rock can also trap the sand until it blocks the source
+        Part::One => {
+            while cavity.get(SOURCE) == Tile::Air {
+                let sand = drop_sand(&cavity, cavity.bottom, recorder);
+                if sand.y < cavity.bottom {
+                    cavity.set(sand, Tile::Sand);
+                    resting += 1;
+                } else {
+                    break;
+                }
+            }
+        }
+        Part::Two => {
+            while cavity.get(SOURCE) == Tile::Air {
+                // comes to rest on boulders, sand or the floor
+                let sand = drop_sand(&cavity, cavity.bottom + 1, recorder);
+                cavity.set(sand, Tile::Sand);
+                resting += 1;
+            }
+        }
+    }
+
+    resting
+}
+
+impl Puzzle for Day14 {
+    const DAY: u32 = 14;
+
+    type Input = Cavity;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let boulder_sequences = contents
+            .lines()
+            .map(|line| {
+                let corners = line
+                    .split(" -> ")
+                    .map(|coordinate_str| {
+                        let (x, y) =
+                            split_once(contents, coordinate_str, ",", "a point like `498,4`")?;
+                        // the sand falls from y 0 so rock is never above it
+                        let y = parse_number::<u16>(contents, y)?;
+                        Ok((
+                            Point::new(parse_number(contents, x)?, y as i32),
+                            coordinate_str,
+                        ))
+                    })
+                    .collect::<Result<Vec<(Point, &str)>, ParseError>>()?;
+                for ((start, _), (stop, stop_str)) in corners.iter().tuple_windows() {
+                    if start.x != stop.x && start.y != stop.y {
+                        return Err(ParseError::new(
+                            contents,
+                            stop_str,
+                            "a point in the same row or column as the one before",
+                        ));
+                    }
+                }
+                Ok(corners.into_iter().map(|(corner, _)| corner).collect())
+            })
+            .collect::<Result<Vec<BoulderSequence>, ParseError>>()?;
+
+        let bottom = boulder_sequences
+            .iter()
+            .flatten()
+            .map(|pos| pos.y)
+            .max()
+            .unwrap_or(0);
+        // the sand piles up to a triangle below the source at most
+        let (left, right) = boulder_sequences
+            .iter()
+            .flatten()
+            .map(|pos| pos.x)
+            .chain([SOURCE.x - bottom - 2, SOURCE.x + bottom + 2])
+            .minmax()
+            .into_option()
+            .unwrap();
+
+        let mut cavity = Cavity {
+            tiles: Grid::new((right - left + 1) as usize, bottom as usize + 2, Tile::Air),
+            left,
+            bottom,
+        };
+        for boulder_sequence in &boulder_sequences {
+            for (start, stop) in boulder_sequence.iter().tuple_windows() {
+                // walk the straight line of rock one step at a time
+                let mut position = *start;
+                cavity.set(position, Tile::Rock);
+                while position != *stop {
+                    position = position.step_toward(*stop);
+                    cavity.set(position, Tile::Rock);
+                }
+            }
+        }
+        Ok(cavity)
+    }
+
+    fn part1(&self, cavity: &Self::Input) -> usize {
+        pour_sand(cavity, Part::One, &mut Recorder::off())
+    }
+
+    fn part2(&self, cavity: &Self::Input) -> usize {
+        pour_sand(cavity, Part::Two, &mut Recorder::off())
+    }
+}
+
+impl Visualize for Day14 {
+    fn visualize(&self, cavity: &Self::Input, part: Part, recorder: &mut Recorder) -> Answer {
+        pour_sand(cavity, part, recorder).into()
+    }
+}
+
+impl Generate for Day14 {
+    /// `size` paths of rock, each two to five straight lines, below the source
+    fn generate(&self, rng: &mut StdRng, size: usize) -> String {
+        let depth = 10 + size as i32;
+        (0..size.max(1))
+            .map(|_| {
+                let mut position = Point::new(
+                    SOURCE.x + rng.gen_range(-depth / 2, depth / 2 + 1),
+                    rng.gen_range(2, depth + 1),
+                );
+                let mut path = vec![position];
+                let horizontal_first = rng.gen_bool(0.5);
+                for line in 0..rng.gen_range(2, 6) {
+                    let length = rng.gen_range(1, 9) * if rng.gen_bool(0.5) { 1 } else { -1 };
+                    position = if (line % 2 == 0) == horizontal_first {
+                        position + Point::new(length, 0)
+                    } else {
+                        // rock stays below the source
+                        Point::new(position.x, (position.y + length).max(1))
+                    };
+                    if position != *path.last().unwrap() {
+                        path.push(position);
+                    }
+                }
+                path.iter().join(" -> ") + "\n"
+            })
+            .collect()
+    }
+}
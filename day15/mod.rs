@@ -0,0 +1,241 @@
+use crate::common::error::{parse_number, split_once};
+use crate::common::puzzle::Command;
+use crate::common::{Part, Point, Solver};
+use crate::{ParseError, Puzzle};
+use itertools::Itertools;
+use std::process;
+
+/// Searches the row and square of the full input, the example's are set
+/// with `search`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day15 {
+    pub search: Search,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Day15 {
+            search: Search::FULL,
+        }
+    }
+}
+
+/// A sensor and the closest beacon to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sensor {
+    pub position: Point,
+    pub beacon: Point,
+}
+
+impl Sensor {
+    /// No other beacon is this close or closer to the sensor
+    pub fn range(&self) -> i32 {
+        self.position.manhattan(self.beacon)
+    }
+
+    pub fn covers(&self, position: Point) -> bool {
+        self.position.manhattan(position) <= self.range()
+    }
+
+    /// First and last column of `row` in range, if the range reaches the row
+    fn columns(&self, row: i32) -> Option<(i32, i32)> {
+        let reach = self.range() - (self.position.y - row).abs();
+        (reach >= 0).then(|| (self.position.x - reach, self.position.x + reach))
+    }
+}
+
+/// The row part one counts in and the square from 0,0 to `bound`,`bound`
+/// part two searches, which are smaller for the example
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Search {
+    pub row: i32,
+    pub bound: i32,
+}
+
+impl Search {
+    pub const EXAMPLE: Search = Search { row: 10, bound: 20 };
+    pub const FULL: Search = Search {
+        row: 2_000_000,
+        bound: 4_000_000,
+    };
+
+    /// This search with the row and bound given as `--row` and `--bound`
+    /// on the command line, where they are
+    pub fn with_options(self, row: Option<&str>, bound: Option<&str>) -> Result<Search, String> {
+        let mut search = self;
+        if let Some(row) = row {
+            search.row = row
+                .parse()
+                .map_err(|_| format!("`{}` is not a row for --row", row))?;
+        }
+        if let Some(bound) = bound {
+            search.bound = match bound.parse() {
+                Ok(bound) if bound >= 0 => bound,
+                _ => return Err(format!("`{}` is not a bound for --bound", bound)),
+            };
+        }
+        Ok(search)
+    }
+}
+
+/// `intervals` of columns joined where they overlap or touch, from left to right
+fn merge(mut intervals: Vec<(i32, i32)>) -> Vec<(i32, i32)> {
+    intervals.sort_unstable();
+    let mut merged: Vec<(i32, i32)> = Vec::with_capacity(intervals.len());
+    for (first, last) in intervals {
+        match merged.last_mut() {
+            Some((_, end)) if first <= *end + 1 => *end = (*end).max(last),
+            _ => merged.push((first, last)),
+        }
+    }
+    merged
+}
+
+fn covered_columns(sensors: &[Sensor], row: i32) -> Vec<(i32, i32)> {
+    merge(
+        sensors
+            .iter()
+            .filter_map(|sensor| sensor.columns(row))
+            .collect(),
+    )
+}
+
+/// Positions in `row` where the beacon can't be
+pub fn excluded(sensors: &[Sensor], row: i32) -> usize {
+    let covered = covered_columns(sensors, row)
+        .iter()
+        .map(|(first, last)| (last - first + 1) as usize)
+        .sum::<usize>();
+    // a beacon in the row is always in range of its own sensor
+    let beacons = sensors
+        .iter()
+        .map(|sensor| sensor.beacon)
+        .filter(|beacon| beacon.y == row)
+        .unique()
+        .count();
+    covered - beacons
+}
+
+/// Where the diagonal lines just outside the ranges of two sensors cross.
+/// A single uncovered position is boxed in by ranges, so unless it's on the
+/// edge of the search it is on one of these crossings.
+fn crossings(sensors: &[Sensor]) -> impl Iterator<Item = Point> + '_ {
+    // rising lines are y - x = c, falling ones are y + x = d
+    let rising = sensors
+        .iter()
+        .flat_map(|sensor| {
+            let c = sensor.position.y - sensor.position.x;
+            [c - sensor.range() - 1, c + sensor.range() + 1]
+        })
+        .unique()
+        .collect::<Vec<i32>>();
+    let falling = sensors
+        .iter()
+        .flat_map(|sensor| {
+            let d = sensor.position.y + sensor.position.x;
+            [d - sensor.range() - 1, d + sensor.range() + 1]
+        })
+        .unique()
+        .collect::<Vec<i32>>();
+
+    rising
+        .into_iter()
+        .cartesian_product(falling)
+        .filter(|(c, d)| (d - c) % 2 == 0)
+        .map(|(c, d)| Point::new((d - c) / 2, (d + c) / 2))
+}
+
+/// The only position in the square from 0,0 to `bound`,`bound` out of range
+/// of all sensors
+pub fn distress_beacon(sensors: &[Sensor], bound: i32) -> Option<Point> {
+    let in_search =
+        |position: &Point| (0..=bound).contains(&position.x) && (0..=bound).contains(&position.y);
+    let uncovered = |position: &Point| !sensors.iter().any(|sensor| sensor.covers(*position));
+    if let Some(position) = crossings(sensors).filter(in_search).find(uncovered) {
+        return Some(position);
+    }
+
+    // on the edge of the search, look for the gap row by row
+    (0..=bound).find_map(|row| {
+        let mut column = 0;
+        for (first, last) in covered_columns(sensors, row) {
+            if first > column {
+                break;
+            }
+            column = column.max(last + 1);
+        }
+        (column <= bound).then(|| Point::new(column, row))
+    })
+}
+
+pub fn tuning_frequency(position: Point) -> i64 {
+    position.x as i64 * 4_000_000 + position.y as i64
+}
+
+/// `x=2, y=18`
+fn parse_position(contents: &str, text: &str) -> Result<Point, ParseError> {
+    let (x, y) = split_once(contents, text, ", y=", "a position like `x=2, y=18`")?;
+    let x = x
+        .strip_prefix("x=")
+        .ok_or_else(|| ParseError::new(contents, x, "`x=`"))?;
+    Ok(Point::new(
+        parse_number(contents, x)?,
+        parse_number(contents, y)?,
+    ))
+}
+
+impl Puzzle for Day15 {
+    const DAY: u32 = 15;
+
+    type Input = Vec<Sensor>;
+    type Output1 = usize;
+    type Output2 = i64;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        contents
+            .lines()
+            .map(|line| {
+                let sensor = line.strip_prefix("Sensor at ").ok_or_else(|| {
+                    ParseError::new(
+                        contents,
+                        line,
+                        "a line like `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`",
+                    )
+                })?;
+                let (sensor, beacon) = split_once(
+                    contents,
+                    sensor,
+                    ": closest beacon is at ",
+                    "`: closest beacon is at ` after the sensor",
+                )?;
+                Ok(Sensor {
+                    position: parse_position(contents, sensor)?,
+                    beacon: parse_position(contents, beacon)?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(&self, sensors: &Self::Input) -> usize {
+        excluded(sensors, self.search.row)
+    }
+
+    fn part2(&self, sensors: &Self::Input) -> i64 {
+        // 0 when every position is covered
+        distress_beacon(sensors, self.search.bound).map_or(0, tuning_frequency)
+    }
+}
+
+/// Entry point of the day 15 binary, like `run` with `--row` and `--bound`
+/// to search the example's row and square, or any other, instead of the
+/// full input's
+pub fn run() {
+    let command = Command::parse(&["row", "bound"], " [--row <row>] [--bound <bound>]");
+    let search = Search::FULL
+        .with_options(command.args.option("row"), command.args.option("bound"))
+        .unwrap_or_else(|error| command.fail(&error));
+    let puzzle = Day15 { search };
+    if !command.solve_inputs(Day15::DAY, |contents| puzzle.solve(contents, &Part::BOTH)) {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::common::error::{parse_number, split_once};
+use crate::{ParseError, Puzzle};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day16;
+
+const START: &str = "AA";
+
+/// More than this many valves with a flow rate take too much memory for a
+/// best release per set of them
+const MAX_USEFUL: usize = 20;
+
+/// A valve as scanned, with the valves its tunnels lead to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Valve {
+    pub name: String,
+    pub flow_rate: u32,
+    pub tunnels: Vec<String>,
+}
+
+/// The valves worth opening and the minutes between them, the valves that
+/// don't release anything are only walked through
+#[derive(Debug, Clone)]
+pub struct Network {
+    pub names: Vec<String>,
+    pub flow_rates: Vec<u32>,
+    /// Minutes to walk from one valve to another, by index
+    pub distances: Vec<Vec<u32>>,
+    /// Minutes to walk from the start to each valve
+    pub from_start: Vec<u32>,
+}
+
+impl Network {
+    /// Shortest walks between all valves, kept for the start and the valves
+    /// with a flow rate. `valves` has the one to start at and every tunnel
+    /// leads to one of them.
+    pub fn compress(valves: &[Valve]) -> Network {
+        let index = valves
+            .iter()
+            .enumerate()
+            .map(|(index, valve)| (valve.name.as_str(), index))
+            .collect::<HashMap<&str, usize>>();
+        let start = index[START];
+
+        // Floyd-Warshall, unreachable valves stay at u32::MAX
+        let count = valves.len();
+        let mut distances = vec![vec![u32::MAX; count]; count];
+        for (from, valve) in valves.iter().enumerate() {
+            distances[from][from] = 0;
+            for tunnel in &valve.tunnels {
+                distances[from][index[tunnel.as_str()]] = 1;
+            }
+        }
+        for via in 0..count {
+            for from in 0..count {
+                for to in 0..count {
+                    let walk = distances[from][via].saturating_add(distances[via][to]);
+                    if walk < distances[from][to] {
+                        distances[from][to] = walk;
+                    }
+                }
+            }
+        }
+
+        let useful = (0..count)
+            .filter(|valve| valves[*valve].flow_rate > 0)
+            .collect::<Vec<usize>>();
+        Network {
+            names: useful
+                .iter()
+                .map(|valve| valves[*valve].name.clone())
+                .collect(),
+            flow_rates: useful
+                .iter()
+                .map(|valve| valves[*valve].flow_rate)
+                .collect(),
+            distances: useful
+                .iter()
+                .map(|from| useful.iter().map(|to| distances[*from][*to]).collect())
+                .collect(),
+            from_start: useful
+                .iter()
+                .map(|valve| distances[start][*valve])
+                .collect(),
+        }
+    }
+
+    /// The most pressure released in `minutes` by opening exactly the valves
+    /// in each set, a bit per valve. Sets that can't be opened in time have
+    /// nothing.
+    pub fn best_per_set(&self, minutes: u32) -> Vec<u32> {
+        let mut best = vec![0; 1 << self.flow_rates.len()];
+        for (valve, distance) in self.from_start.iter().enumerate() {
+            // a minute to walk each tunnel and one to open the valve
+            if let Some(left) = minutes.checked_sub(distance.saturating_add(1)) {
+                self.visit(
+                    valve,
+                    1 << valve,
+                    left,
+                    left * self.flow_rates[valve],
+                    &mut best,
+                );
+            }
+        }
+        best
+    }
+
+    fn visit(&self, at: usize, opened: usize, left: u32, released: u32, best: &mut [u32]) {
+        best[opened] = best[opened].max(released);
+        for (next, distance) in self.distances[at].iter().enumerate() {
+            if opened & (1 << next) != 0 {
+                continue;
+            }
+            if let Some(left) = left.checked_sub(distance.saturating_add(1)) {
+                let released = released + left * self.flow_rates[next];
+                self.visit(next, opened | (1 << next), left, released, best);
+            }
+        }
+    }
+}
+
+/// For every set, the best of `best` over its subsets
+fn best_within(mut best: Vec<u32>) -> Vec<u32> {
+    let valves = best.len().trailing_zeros();
+    for valve in 0..valves {
+        for set in 0..best.len() {
+            if set & (1 << valve) != 0 {
+                best[set] = best[set].max(best[set ^ (1 << valve)]);
+            }
+        }
+    }
+    best
+}
+
+/// The most pressure released alone in 30 minutes
+pub fn release_alone(network: &Network) -> usize {
+    network.best_per_set(30).into_iter().max().unwrap_or(0) as usize
+}
+
+/// The most pressure released in 26 minutes with an elephant, each opening
+/// their own valves
+pub fn release_with_elephant(network: &Network) -> usize {
+    let best = best_within(network.best_per_set(26));
+    let all = best.len() - 1;
+    (0..best.len())
+        .map(|mine| best[mine] + best[all ^ mine])
+        .max()
+        .unwrap_or(0) as usize
+}
+
+impl Puzzle for Day16 {
+    const DAY: u32 = 16;
+
+    type Input = Network;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let expected = "a line like `Valve BB has flow rate=13; tunnels lead to valves CC, AA`";
+        let valves = contents
+            .lines()
+            .map(|line| {
+                let valve = line
+                    .strip_prefix("Valve ")
+                    .ok_or_else(|| ParseError::new(contents, line, expected))?;
+                let (name, rest) = split_once(contents, valve, " has flow rate=", expected)?;
+                let (flow_rate, tunnels) = split_once(contents, rest, "; ", expected)?;
+                let tunnels = tunnels
+                    .strip_prefix("tunnels lead to valves ")
+                    .or_else(|| tunnels.strip_prefix("tunnel leads to valve "))
+                    .ok_or_else(|| {
+                        ParseError::new(contents, tunnels, "`tunnels lead to valves `")
+                    })?;
+                Ok((name, parse_number(contents, flow_rate)?, tunnels))
+            })
+            .collect::<Result<Vec<(&str, u32, &str)>, ParseError>>()?;
+
+        let mut names = HashSet::new();
+        for (name, _, _) in &valves {
+            if !names.insert(*name) {
+                return Err(ParseError::new(
+                    contents,
+                    name,
+                    "a valve not scanned before",
+                ));
+            }
+        }
+        if !names.contains(START) {
+            return Err(ParseError::new(
+                contents,
+                &contents[contents.len()..],
+                format!("a valve {} to start at", START),
+            ));
+        }
+        if valves
+            .iter()
+            .filter(|(_, flow_rate, _)| *flow_rate > 0)
+            .count()
+            > MAX_USEFUL
+        {
+            return Err(ParseError::new(
+                contents,
+                &contents[contents.len()..],
+                format!("at most {} valves with a flow rate", MAX_USEFUL),
+            ));
+        }
+        let valves = valves
+            .into_iter()
+            .map(|(name, flow_rate, tunnels)| {
+                let tunnels = tunnels
+                    .split(", ")
+                    .map(|tunnel| {
+                        if names.contains(tunnel) {
+                            Ok(tunnel.to_string())
+                        } else {
+                            Err(ParseError::new(contents, tunnel, "a scanned valve"))
+                        }
+                    })
+                    .collect::<Result<Vec<String>, ParseError>>()?;
+                Ok(Valve {
+                    name: name.to_string(),
+                    flow_rate,
+                    tunnels,
+                })
+            })
+            .collect::<Result<Vec<Valve>, ParseError>>()?;
+        Ok(Network::compress(&valves))
+    }
+
+    fn part1(&self, network: &Self::Input) -> usize {
+        release_alone(network)
+    }
+
+    fn part2(&self, network: &Self::Input) -> usize {
+        release_with_elephant(network)
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{ParseError, Puzzle};
+use std::collections::HashMap;
+use std::fmt;
+
+pub struct Day17;
+
+const WIDTH: usize = 7;
+
+/// Rows of a rock from the bottom up, a bit per column with the leftmost
+/// column in the lowest bit, placed two columns from the left wall
+const ROCKS: [&[u8]; 5] = [
+    &[0b0111100],
+    &[0b0001000, 0b0011100, 0b0001000],
+    &[0b0011100, 0b0010000, 0b0010000],
+    &[0b0000100, 0b0000100, 0b0000100, 0b0000100],
+    &[0b0001100, 0b0001100],
+];
+
+/// Columns of the left and the right wall
+const LEFT: u8 = 0b0000001;
+const RIGHT: u8 = 0b1000000;
+
+/// Rows below the top of the tower that tell surfaces apart, rocks don't
+/// fall deeper than this into the tower
+const SURFACE_DEPTH: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+/// A rock on its way down
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Falling {
+    rows: Vec<u8>,
+    /// Row of the bottom of the rock
+    bottom: usize,
+}
+
+/// The tall, narrow chamber with the rocks that came to rest in it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Chamber {
+    /// From the floor up, without empty rows on top
+    rows: Vec<u8>,
+    /// How many rocks came to rest
+    pub rocks: usize,
+    /// How many jets pushed the rocks
+    pub jets: usize,
+}
+
+impl Chamber {
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn hits(&self, rows: &[u8], bottom: usize) -> bool {
+        rows.iter()
+            .zip(self.rows.iter().skip(bottom))
+            .any(|(rock, row)| rock & row != 0)
+    }
+
+    /// The next rock, three rows above the highest rock or the floor
+    pub fn appear(&self) -> Falling {
+        Falling {
+            rows: ROCKS[self.rocks % ROCKS.len()].to_vec(),
+            bottom: self.height() + 3,
+        }
+    }
+
+    /// Pushes `falling` sideways with the next of `jets` and moves it down,
+    /// where it comes to rest if it can't. Returns whether it's still falling.
+    pub fn step(&mut self, falling: &mut Falling, jets: &[Jet]) -> bool {
+        let jet = jets[self.jets % jets.len()];
+        self.jets += 1;
+        let pushed = match jet {
+            Jet::Left if falling.rows.iter().all(|row| row & LEFT == 0) => {
+                falling.rows.iter().map(|row| row >> 1).collect()
+            }
+            Jet::Right if falling.rows.iter().all(|row| row & RIGHT == 0) => {
+                falling.rows.iter().map(|row| row << 1).collect()
+            }
+            _ => falling.rows.clone(),
+        };
+        if !self.hits(&pushed, falling.bottom) {
+            falling.rows = pushed;
+        }
+
+        if falling.bottom > 0 && !self.hits(&falling.rows, falling.bottom - 1) {
+            falling.bottom -= 1;
+            return true;
+        }
+        for (offset, rock) in falling.rows.iter().enumerate() {
+            match self.rows.get_mut(falling.bottom + offset) {
+                Some(row) => *row |= rock,
+                None => self.rows.push(*rock),
+            }
+        }
+        self.rocks += 1;
+        false
+    }
+
+    /// Lets the next rock fall until it comes to rest
+    pub fn drop_rock(&mut self, jets: &[Jet]) {
+        let mut falling = self.appear();
+        while self.step(&mut falling, jets) {}
+    }
+
+    /// How far down from the top of the tower each column is blocked, up to
+    /// `SURFACE_DEPTH` for columns the rocks never fill
+    fn surface(&self) -> [usize; WIDTH] {
+        let mut depths = [SURFACE_DEPTH; WIDTH];
+        for (column, depth) in depths.iter_mut().enumerate() {
+            if let Some(top) = self
+                .rows
+                .iter()
+                .rev()
+                .take(SURFACE_DEPTH)
+                .position(|row| row & (1 << column) != 0)
+            {
+                *depth = top;
+            }
+        }
+        depths
+    }
+
+    /// The tower like the puzzle draws it, `@` for the `falling` rock
+    pub fn draw(&self, falling: Option<&Falling>) -> String {
+        let top = falling.map_or(self.height(), |falling| {
+            self.height().max(falling.bottom + falling.rows.len())
+        });
+        let mut picture = String::new();
+        for y in (0..top).rev() {
+            let row = self.rows.get(y).copied().unwrap_or(0);
+            let rock = falling
+                .and_then(|falling| falling.rows.get(y.checked_sub(falling.bottom)?))
+                .copied()
+                .unwrap_or(0);
+            picture.push('|');
+            for column in 0..WIDTH {
+                picture.push(if rock & (1 << column) != 0 {
+                    '@'
+                } else if row & (1 << column) != 0 {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            picture.push_str("|\n");
+        }
+        picture.push('+');
+        picture.push_str(&"-".repeat(WIDTH));
+        picture.push_str("+\n");
+        picture
+    }
+}
+
+impl fmt::Display for Chamber {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.draw(None))
+    }
+}
+
+/// The chamber after `rocks` rocks came to rest
+pub fn simulate(jets: &[Jet], rocks: usize) -> Chamber {
+    let mut chamber = Chamber::default();
+    while chamber.rocks < rocks {
+        chamber.drop_rock(jets);
+    }
+    chamber
+}
+
+/// Height of the tower after `rocks` rocks, however many. Once the next rock,
+/// the next jet and the surface of the tower are the same as before, rocks
+/// keep piling up the same way, so the repeats are skipped.
+pub fn tower_height(jets: &[Jet], rocks: usize) -> usize {
+    let mut chamber = Chamber::default();
+    let mut seen = HashMap::new();
+    let mut skipped = 0;
+    while chamber.rocks < rocks {
+        chamber.drop_rock(jets);
+        if skipped > 0 {
+            continue;
+        }
+        let state = (
+            chamber.rocks % ROCKS.len(),
+            chamber.jets % jets.len(),
+            chamber.surface(),
+        );
+        if let Some((before, height)) = seen.insert(state, (chamber.rocks, chamber.height())) {
+            let period = chamber.rocks - before;
+            let repeats = (rocks - chamber.rocks) / period;
+            skipped = repeats * (chamber.height() - height);
+            chamber.rocks += repeats * period;
+        }
+    }
+    chamber.height() + skipped
+}
+
+impl Puzzle for Day17 {
+    const DAY: u32 = 17;
+
+    type Input = Vec<Jet>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let line = contents.trim_end_matches('\n');
+        if line.is_empty() {
+            return Err(ParseError::new(contents, line, "a jet pattern"));
+        }
+        line.char_indices()
+            .map(|(index, jet)| match jet {
+                '<' => Ok(Jet::Left),
+                '>' => Ok(Jet::Right),
+                _ => Err(ParseError::new(
+                    contents,
+                    &line[index..index + jet.len_utf8()],
+                    "`<` or `>`",
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(&self, jets: &Self::Input) -> usize {
+        simulate(jets, 2022).height()
+    }
+
+    fn part2(&self, jets: &Self::Input) -> usize {
+        tower_height(jets, 1_000_000_000_000)
+    }
+}
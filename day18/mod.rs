@@ -0,0 +1,75 @@
+use crate::common::error::parse_number;
+use crate::common::{BoundingBox3, Point3};
+use crate::{ParseError, Puzzle};
+use std::collections::HashSet;
+
+pub struct Day18;
+
+/// The 1×1×1 cubes of lava in the scan
+pub type Droplet = HashSet<Point3>;
+
+/// Faces of the cubes that don't touch another cube, including those of air
+/// pockets trapped inside
+pub fn surface_area(droplet: &Droplet) -> usize {
+    droplet
+        .iter()
+        .flat_map(|cube| cube.neighbours6())
+        .filter(|neighbour| !droplet.contains(neighbour))
+        .count()
+}
+
+/// Faces that water flowing around the droplet can reach. The water fills
+/// a box one larger than the droplet on every side, so it flows all the way
+/// around.
+pub fn exterior_surface_area(droplet: &Droplet) -> usize {
+    let bounds = match BoundingBox3::from_points(droplet.iter().copied()) {
+        Some(bounds) => bounds.grown(1),
+        None => return 0,
+    };
+
+    let mut water = HashSet::from([bounds.min]);
+    let mut queue = vec![bounds.min];
+    let mut faces = 0;
+    while let Some(position) = queue.pop() {
+        for neighbour in position.neighbours6() {
+            if droplet.contains(&neighbour) {
+                faces += 1;
+            } else if bounds.contains(neighbour) && water.insert(neighbour) {
+                queue.push(neighbour);
+            }
+        }
+    }
+    faces
+}
+
+impl Puzzle for Day18 {
+    const DAY: u32 = 18;
+
+    type Input = Droplet;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        contents
+            .lines()
+            .map(|line| {
+                let coordinates = line
+                    .split(',')
+                    .map(|coordinate| parse_number(contents, coordinate))
+                    .collect::<Result<Vec<i32>, ParseError>>()?;
+                match coordinates[..] {
+                    [x, y, z] => Ok(Point3::new(x, y, z)),
+                    _ => Err(ParseError::new(contents, line, "a cube like `2,2,2`")),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(&self, droplet: &Self::Input) -> usize {
+        surface_area(droplet)
+    }
+
+    fn part2(&self, droplet: &Self::Input) -> usize {
+        exterior_surface_area(droplet)
+    }
+}
@@ -0,0 +1,190 @@
+use crate::common::error::parse_number;
+use crate::{ParseError, Puzzle};
+use rayon::prelude::*;
+
+pub struct Day19;
+
+/// A blueprint word by word, `{}` is a number
+const TEMPLATE: &str = "Blueprint {}: Each ore robot costs {} ore. \
+    Each clay robot costs {} ore. \
+    Each obsidian robot costs {} ore and {} clay. \
+    Each geode robot costs {} ore and {} obsidian.";
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blueprint {
+    pub id: u32,
+    /// Ore, clay and obsidian for a robot of each kind, by what it collects
+    pub costs: [[u32; 3]; 4],
+}
+
+impl Blueprint {
+    /// More robots collecting a resource than the most any robot costs of it
+    /// can't help, only one robot is built each minute
+    fn useful_robots(&self) -> [u32; 3] {
+        let mut useful = [0; 3];
+        for cost in &self.costs {
+            for (resource, amount) in cost.iter().enumerate() {
+                useful[resource] = useful[resource].max(*amount);
+            }
+        }
+        useful
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    minutes: u32,
+    robots: [u32; 4],
+    stock: [u32; 4],
+}
+
+impl State {
+    /// Minutes until the robots collected enough for `cost`, `None` if they
+    /// never will
+    fn wait(&self, cost: &[u32; 3]) -> Option<u32> {
+        (0..3).try_fold(0, |wait, resource| {
+            let missing = cost[resource].saturating_sub(self.stock[resource]);
+            match (missing, self.robots[resource]) {
+                (0, _) => Some(wait),
+                (_, 0) => None,
+                (missing, robots) => Some(wait.max(missing.div_ceil(robots))),
+            }
+        })
+    }
+
+    /// Geodes at the end if a geode robot was built every minute from now
+    fn optimistic(&self) -> u32 {
+        let minutes = self.minutes;
+        self.stock[GEODE] + self.robots[GEODE] * minutes + minutes * minutes.saturating_sub(1) / 2
+    }
+}
+
+/// Branches on the robot to build next, waiting as long as it takes to
+/// afford it. Branches that can't beat `best` even optimistically are cut.
+fn search(blueprint: &Blueprint, useful: &[u32; 3], state: State, best: &mut u32) {
+    // building nothing more still cracks geodes with the robots there are
+    *best = (*best).max(state.stock[GEODE] + state.robots[GEODE] * state.minutes);
+    if state.optimistic() <= *best {
+        return;
+    }
+
+    for robot in [GEODE, OBSIDIAN, CLAY, ORE] {
+        // enough of the resource to build the priciest robot every minute left
+        if robot != GEODE
+            && state.robots[robot] * state.minutes + state.stock[robot]
+                >= useful[robot] * state.minutes
+        {
+            continue;
+        }
+        let cost = &blueprint.costs[robot];
+        let wait = match state.wait(cost) {
+            // a robot built in the last minute doesn't collect anything
+            Some(wait) if wait + 1 < state.minutes => wait,
+            _ => continue,
+        };
+
+        let mut next = state;
+        next.minutes -= wait + 1;
+        for (stock, robots) in next.stock.iter_mut().zip(state.robots) {
+            *stock += robots * (wait + 1);
+        }
+        for (stock, amount) in next.stock.iter_mut().zip(cost) {
+            *stock -= amount;
+        }
+        next.robots[robot] += 1;
+        search(blueprint, useful, next, best);
+    }
+}
+
+/// Most geodes cracked in `minutes`, starting with one ore robot
+pub fn max_geodes(blueprint: &Blueprint, minutes: u32) -> u32 {
+    let start = State {
+        minutes,
+        robots: [1, 0, 0, 0],
+        stock: [0; 4],
+    };
+    let mut best = 0;
+    search(blueprint, &blueprint.useful_robots(), start, &mut best);
+    best
+}
+
+/// Sum of the ids times the most geodes in 24 minutes
+pub fn quality_levels(blueprints: &[Blueprint]) -> usize {
+    blueprints
+        .par_iter()
+        .map(|blueprint| blueprint.id as usize * max_geodes(blueprint, 24) as usize)
+        .sum()
+}
+
+/// Product of the most geodes in 32 minutes of the first three blueprints
+pub fn first_three(blueprints: &[Blueprint]) -> usize {
+    blueprints
+        .par_iter()
+        .take(3)
+        .map(|blueprint| max_geodes(blueprint, 32) as usize)
+        .product()
+}
+
+impl Puzzle for Day19 {
+    const DAY: u32 = 19;
+
+    type Input = Vec<Blueprint>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    /// Blueprints on a line each or spread over several, like in the puzzle
+    /// description, only the words count
+    fn parse(&self, contents: &str) -> Result<Self::Input, ParseError> {
+        let template = TEMPLATE.split_whitespace().collect::<Vec<&str>>();
+        let mut words = contents.split_whitespace().peekable();
+        let mut blueprints = Vec::new();
+
+        while words.peek().is_some() {
+            let mut numbers = Vec::with_capacity(7);
+            for expected in &template {
+                let word = words.next().unwrap_or(&contents[contents.len()..]);
+                let mismatch = || ParseError::new(contents, word, format!("`{}`", expected));
+                match expected.split_once("{}") {
+                    Some((prefix, suffix)) => {
+                        let number = word
+                            .strip_prefix(prefix)
+                            .and_then(|word| word.strip_suffix(suffix))
+                            .ok_or_else(mismatch)?;
+                        numbers.push(parse_number::<u32>(contents, number)?);
+                    }
+                    None if word == *expected => {}
+                    None => return Err(mismatch()),
+                }
+            }
+
+            let [id, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] =
+                numbers[..]
+            else {
+                unreachable!("the template has seven numbers");
+            };
+            blueprints.push(Blueprint {
+                id,
+                costs: [
+                    [ore, 0, 0],
+                    [clay, 0, 0],
+                    [obsidian_ore, obsidian_clay, 0],
+                    [geode_ore, 0, geode_obsidian],
+                ],
+            });
+        }
+        Ok(blueprints)
+    }
+
+    fn part1(&self, blueprints: &Self::Input) -> usize {
+        quality_levels(blueprints)
+    }
+
+    fn part2(&self, blueprints: &Self::Input) -> usize {
+        first_three(blueprints)
+    }
+}
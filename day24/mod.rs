@@ -0,0 +1,23 @@
+use crate::{ParseError, Puzzle};
+
+pub struct Day24;
+
+impl Puzzle for Day24 {
+    const DAY: u32 = 24;
+
+    type Input = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(&self, _contents: &str) -> Result<Self::Input, ParseError> {
+        Ok(())
+    }
+
+    fn part1(&self, _input: &Self::Input) -> usize {
+        0
+    }
+
+    fn part2(&self, _input: &Self::Input) -> usize {
+        0
+    }
+}
@@ -0,0 +1,61 @@
+use advent_of_code_2022::common::days::InputKind;
+use advent_of_code_2022::common::registry::{self, Registry};
+
+fn verify(day: u32, kind: InputKind) {
+    let registry = Registry::load(&registry::registry_path()).unwrap();
+    for check in registry.check(day, kind).unwrap() {
+        assert!(
+            check.passed(),
+            "day {} part {} on the {} input: expected {}, got {}",
+            day,
+            check.part,
+            kind.name(),
+            check.expected,
+            check.actual
+        );
+    }
+}
+
+/// One module per day with a test for each input listed in answers.toml
+macro_rules! known_answers {
+    ($($name:ident: $day:expr $(, #[$full_attr:meta])?;)*) => {
+        $(
+            mod $name {
+                use super::*;
+
+                #[test]
+                fn small() {
+                    verify($day, InputKind::Small);
+                }
+
+                #[test]
+                $(#[$full_attr])?
+                fn full() {
+                    verify($day, InputKind::Full);
+                }
+            }
+        )*
+    };
+}
+
+known_answers! {
+    day01: 1;
+    day02: 2;
+    day03: 3;
+    day04: 4;
+    day05: 5;
+    day06: 6;
+    day07: 7;
+    day08: 8;
+    day09: 9;
+    day10: 10;
+    day11: 11;
+    day12: 12;
+    day13: 13;
+    day14: 14;
+    day15: 15;
+    day16: 16;
+    day17: 17;
+    day18: 18;
+    day19: 19;
+}
@@ -0,0 +1,91 @@
+use advent_of_code_2022::common::days::{self, InputKind};
+use advent_of_code_2022::common::Point;
+use advent_of_code_2022::day15::{self, Day15, Search, Sensor};
+use advent_of_code_2022::Puzzle;
+use std::process::Command;
+
+fn example() -> Vec<Sensor> {
+    let contents = std::fs::read_to_string(days::input_path(15, InputKind::Small)).unwrap();
+    Day15::default().parse(&contents).unwrap()
+}
+
+#[test]
+fn search_parameters() {
+    let sensors = example();
+    assert_eq!(sensors.len(), 14);
+
+    let puzzle = Day15 {
+        search: Search::EXAMPLE,
+    };
+    assert_eq!(puzzle.part1(&sensors), 26);
+    assert_eq!(puzzle.part2(&sensors), 56000011);
+    // the full input's row is far from the example's sensors
+    assert_eq!(Day15::default().search, Search::FULL);
+    assert_eq!(Day15::default().part1(&sensors), 0);
+
+    // the same sensors with the rows and squares of other searches
+    assert_eq!(day15::excluded(&sensors, 10), 26);
+    assert_eq!(day15::excluded(&sensors, 11), 28);
+    assert_eq!(
+        day15::distress_beacon(&sensors, 20),
+        Some(Point::new(14, 11))
+    );
+    assert_eq!(day15::distress_beacon(&sensors, 10), None);
+    assert_eq!(day15::tuning_frequency(Point::new(14, 11)), 56000011);
+}
+
+#[test]
+fn beacon_in_a_corner() {
+    // covers all of the square from 0,0 to 4,4 but its corner at 0,0
+    let sensors = [Sensor {
+        position: Point::new(4, 4),
+        beacon: Point::new(4, -3),
+    }];
+    assert_eq!(day15::distress_beacon(&sensors, 4), Some(Point::new(0, 0)));
+}
+
+#[test]
+fn malformed() {
+    for contents in [
+        "Sensor at x=2, y=18\n",
+        "Sensor x=2, y=18: closest beacon is at x=-2, y=15\n",
+        "Sensor at x=2, y=18: closest beacon is at -2, 15\n",
+        "Sensor at x=2, y=a: closest beacon is at x=-2, y=15\n",
+    ] {
+        assert!(Day15::default().parse(contents).is_err(), "{}", contents);
+    }
+}
+
+#[test]
+fn search_on_the_command_line() {
+    let small = days::input_path(15, InputKind::Small);
+    let output = Command::new(env!("CARGO_BIN_EXE_day15"))
+        .args(["--row", "10", "--bound", "20"])
+        .arg(&small)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "Part1: 26\nPart2: 56000011\n"
+    );
+
+    // aoc starts from the example's search for the small input
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "run", "15", "--input", "small", "--row", "11", "--format", "json",
+        ])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\"answer\":28,"), "{}", stdout);
+    assert!(stdout.contains("\"answer\":56000011,"), "{}", stdout);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_day15"))
+        .args(["--bound", "-1"])
+        .arg(&small)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,72 @@
+use advent_of_code_2022::common::days::{self, InputKind};
+use advent_of_code_2022::day16::{self, Day16, Network};
+use advent_of_code_2022::Puzzle;
+
+fn example() -> Network {
+    let contents = std::fs::read_to_string(days::input_path(16, InputKind::Small)).unwrap();
+    Day16.parse(&contents).unwrap()
+}
+
+#[test]
+fn compressed() {
+    let network = example();
+    assert_eq!(network.names, ["BB", "CC", "DD", "EE", "HH", "JJ"]);
+    assert_eq!(network.flow_rates, [13, 2, 20, 3, 22, 21]);
+    assert_eq!(network.from_start, [1, 2, 1, 2, 5, 2]);
+    // HH is behind the two valves without flow after EE
+    assert_eq!(network.distances[3][4], 3);
+    assert_eq!(network.distances[4][5], 7);
+}
+
+#[test]
+fn best_per_set() {
+    let network = example();
+    let best = network.best_per_set(30);
+    assert_eq!(best.len(), 1 << 6);
+    // DD opened at minute 2 releases 20 for the remaining 28 minutes
+    assert_eq!(best[1 << 2], 560);
+    assert_eq!(best.iter().max(), Some(&1651));
+    // nothing can be opened without time to walk there
+    assert!(network
+        .best_per_set(1)
+        .iter()
+        .all(|released| *released == 0));
+}
+
+#[test]
+fn no_valves_to_open() {
+    let network = Day16
+        .parse("Valve AA has flow rate=0; tunnel leads to valve BB\nValve BB has flow rate=0; tunnel leads to valve AA\n")
+        .unwrap();
+    assert_eq!(day16::release_alone(&network), 0);
+    assert_eq!(day16::release_with_elephant(&network), 0);
+}
+
+#[test]
+fn malformed() {
+    let error = Day16
+        .parse("Valve AA has flow rate=0; tunnels lead to valves BB, CC\nValve BB has flow rate=3; tunnel leads to valve AA\n")
+        .unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.text.as_str()),
+        (1, 54, "CC")
+    );
+
+    // a second AA, tunnels to it would lead to either
+    let error = Day16
+        .parse("Valve AA has flow rate=0; tunnel leads to valve AA\nValve AA has flow rate=3; tunnel leads to valve AA\n")
+        .unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.text.as_str()),
+        (2, 7, "AA")
+    );
+
+    for contents in [
+        "Valve BB has flow rate=3; tunnel leads to valve BB\n",
+        "Valve AA has flow rate=x; tunnel leads to valve AA\n",
+        "Valve AA has flow rate=0, tunnel leads to valve AA\n",
+        "Valve AA has flow rate=0; tunnels to valve AA\n",
+    ] {
+        assert!(Day16.parse(contents).is_err(), "{}", contents);
+    }
+}
@@ -0,0 +1,108 @@
+use advent_of_code_2022::common::days::{self, InputKind};
+use advent_of_code_2022::day17::{self, Chamber, Day17, Jet};
+use advent_of_code_2022::Puzzle;
+
+fn example() -> Vec<Jet> {
+    let contents = std::fs::read_to_string(days::input_path(17, InputKind::Small)).unwrap();
+    Day17.parse(&contents).unwrap()
+}
+
+#[test]
+fn drawn_like_the_puzzle() {
+    let jets = example();
+    let mut chamber = Chamber::default();
+    assert_eq!(
+        chamber.draw(Some(&chamber.appear())),
+        "\
+|..@@@@.|
+|.......|
+|.......|
+|.......|
++-------+
+"
+    );
+
+    chamber.drop_rock(&jets);
+    let mut falling = chamber.appear();
+    // pushed left and one row down
+    chamber.step(&mut falling, &jets);
+    assert_eq!(
+        chamber.draw(Some(&falling)),
+        "\
+|..@....|
+|.@@@...|
+|..@....|
+|.......|
+|.......|
+|..####.|
++-------+
+"
+    );
+
+    let chamber = day17::simulate(&jets, 10);
+    assert_eq!(
+        chamber.to_string(),
+        "\
+|....#..|
+|....#..|
+|....##.|
+|##..##.|
+|######.|
+|.###...|
+|..#....|
+|.####..|
+|....##.|
+|....##.|
+|....#..|
+|..#.#..|
+|..#.#..|
+|#####..|
+|..###..|
+|...#...|
+|..####.|
++-------+
+"
+    );
+}
+
+#[test]
+fn repeats_are_skipped() {
+    // a pattern that isn't a multiple of the five rocks
+    let jets = Day17.parse("<<>><>>><<<<>>>><<>\n").unwrap();
+    for rocks in [1, 5, 2022, 7777] {
+        assert_eq!(
+            day17::tower_height(&jets, rocks),
+            day17::simulate(&jets, rocks).height(),
+            "{} rocks",
+            rocks
+        );
+        assert_eq!(
+            day17::tower_height(&example(), rocks),
+            day17::simulate(&example(), rocks).height(),
+            "{} rocks",
+            rocks
+        );
+    }
+}
+
+#[test]
+fn one_sided_jets() {
+    // the columns on the other side never fill up
+    for pattern in ["<\n", ">\n"] {
+        let jets = Day17.parse(pattern).unwrap();
+        assert_eq!(
+            day17::tower_height(&jets, 2022),
+            day17::simulate(&jets, 2022).height(),
+            "{}",
+            pattern
+        );
+        assert!(day17::tower_height(&jets, 1_000_000_000_000) > 0);
+    }
+}
+
+#[test]
+fn malformed() {
+    assert!(Day17.parse("").is_err());
+    let error = Day17.parse("<<>-<\n").unwrap_err();
+    assert_eq!((error.column, error.text.as_str()), (4, "-"));
+}
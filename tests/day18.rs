@@ -0,0 +1,33 @@
+use advent_of_code_2022::common::Point3;
+use advent_of_code_2022::day18::{self, Day18, Droplet};
+use advent_of_code_2022::Puzzle;
+
+#[test]
+fn two_cubes() {
+    let droplet = Day18.parse("1,1,1\n2,1,1\n").unwrap();
+    assert_eq!(day18::surface_area(&droplet), 10);
+    assert_eq!(day18::exterior_surface_area(&droplet), 10);
+}
+
+#[test]
+fn air_pocket() {
+    // a 3×3×3 cube with the middle one missing
+    let droplet = (0..27)
+        .map(|index| Point3::new(index % 3, index / 3 % 3, index / 9))
+        .filter(|cube| *cube != Point3::new(1, 1, 1))
+        .collect::<Droplet>();
+    assert_eq!(day18::surface_area(&droplet), 54 + 6);
+    assert_eq!(day18::exterior_surface_area(&droplet), 54);
+
+    assert_eq!(day18::surface_area(&Droplet::new()), 0);
+    assert_eq!(day18::exterior_surface_area(&Droplet::new()), 0);
+}
+
+#[test]
+fn malformed() {
+    for contents in ["1,1\n", "1,1,1,1\n", "1,a,1\n", "1 1 1\n"] {
+        assert!(Day18.parse(contents).is_err(), "{}", contents);
+    }
+    let error = Day18.parse("1,1,1\n2,1\n").unwrap_err();
+    assert_eq!((error.line, error.text.as_str()), (2, "2,1"));
+}
@@ -0,0 +1,60 @@
+use advent_of_code_2022::common::days::{self, InputKind};
+use advent_of_code_2022::day19::{self, Blueprint, Day19};
+use advent_of_code_2022::Puzzle;
+
+fn example() -> Vec<Blueprint> {
+    let contents = std::fs::read_to_string(days::input_path(19, InputKind::Small)).unwrap();
+    Day19.parse(&contents).unwrap()
+}
+
+#[test]
+fn layouts() {
+    let blueprints = example();
+    assert_eq!(blueprints.len(), 2);
+    assert_eq!(
+        blueprints[0].costs,
+        [[4, 0, 0], [2, 0, 0], [3, 14, 0], [2, 0, 7]]
+    );
+
+    // the full inputs have a blueprint per line
+    let one_line = Day19
+        .parse("Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.\n")
+        .unwrap();
+    assert_eq!(one_line, [blueprints[1]]);
+}
+
+#[test]
+fn geodes_per_blueprint() {
+    let blueprints = example();
+    assert_eq!(day19::max_geodes(&blueprints[0], 24), 9);
+    assert_eq!(day19::max_geodes(&blueprints[1], 24), 12);
+    assert_eq!(day19::max_geodes(&blueprints[0], 32), 56);
+    assert_eq!(day19::max_geodes(&blueprints[1], 32), 62);
+    // the first geode robot can't be ready sooner
+    assert_eq!(day19::max_geodes(&blueprints[0], 18), 0);
+}
+
+#[test]
+fn fewer_than_three_blueprints() {
+    let blueprints = example();
+    assert_eq!(day19::first_three(&blueprints[..1]), 56);
+    assert_eq!(day19::quality_levels(&[]), 0);
+}
+
+#[test]
+fn malformed() {
+    let error = Day19
+        .parse("Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 clay.\n")
+        .unwrap_err();
+    assert_eq!(
+        (error.text.as_str(), error.expected.as_str()),
+        ("clay.", "`obsidian.`")
+    );
+
+    let error = Day19
+        .parse("Blueprint 1: Each ore robot costs 4 ore.\n")
+        .unwrap_err();
+    assert_eq!((error.line, error.text.as_str()), (2, ""));
+
+    assert!(Day19.parse("Blueprint x: Each ore robot").is_err());
+}
@@ -0,0 +1,230 @@
+use advent_of_code_2022::common::differential::{
+    shrink_characters, shrink_grid, shrink_lines, Differential,
+};
+use std::collections::{HashSet, VecDeque};
+
+fn check(differential: Differential) {
+    if let Err(disagreement) = differential.run() {
+        panic!("{}", disagreement);
+    }
+}
+
+/// Rows of cells, `None` unless every row is as long as the first
+fn rows(input: &str) -> Option<Vec<Vec<char>>> {
+    let rows = input
+        .lines()
+        .map(|line| line.chars().collect::<Vec<char>>())
+        .collect::<Vec<_>>();
+    let width = rows.first()?.len();
+    if width == 0 || rows.iter().any(|row| row.len() != width) {
+        return None;
+    }
+    Some(rows)
+}
+
+/// Position of the first character after `length` distinct ones
+fn marker(stream: &[char], length: usize) -> Option<usize> {
+    (length..=stream.len()).find(|&end| {
+        stream[end - length..end]
+            .iter()
+            .collect::<HashSet<_>>()
+            .len()
+            == length
+    })
+}
+
+fn day06(input: &str) -> Option<Vec<String>> {
+    let stream = input.lines().next()?.chars().collect::<Vec<char>>();
+    Some(vec![
+        marker(&stream, 4)?.to_string(),
+        marker(&stream, 14)?.to_string(),
+    ])
+}
+
+#[test]
+fn day06_markers() {
+    check(
+        Differential::new(6, day06)
+            .shrink(shrink_characters)
+            .sizes(14, 60),
+    );
+}
+
+fn day08(input: &str) -> Option<Vec<String>> {
+    let trees = rows(input)?;
+    let (width, height) = (trees[0].len(), trees.len());
+    let mut visible = 0;
+    let mut best_score = 0;
+
+    for y in 0..height {
+        for x in 0..width {
+            let tree = trees[y][x];
+            let lines_of_sight: [Vec<char>; 4] = [
+                (0..y).rev().map(|y| trees[y][x]).collect(),
+                (y + 1..height).map(|y| trees[y][x]).collect(),
+                (0..x).rev().map(|x| trees[y][x]).collect(),
+                (x + 1..width).map(|x| trees[y][x]).collect(),
+            ];
+
+            if lines_of_sight
+                .iter()
+                .any(|line| line.iter().all(|other| *other < tree))
+            {
+                visible += 1;
+            }
+            let score: usize = lines_of_sight
+                .iter()
+                .map(|line| match line.iter().position(|other| *other >= tree) {
+                    Some(blocked) => blocked + 1,
+                    None => line.len(),
+                })
+                .product();
+            best_score = best_score.max(score);
+        }
+    }
+    Some(vec![visible.to_string(), best_score.to_string()])
+}
+
+#[test]
+fn day08_trees() {
+    check(Differential::new(8, day08).shrink(shrink_grid).sizes(1, 12));
+}
+
+fn day12(input: &str) -> Option<Vec<String>> {
+    let map = rows(input)?;
+    let find = |marker: char| {
+        map.iter()
+            .enumerate()
+            .find_map(|(y, row)| row.iter().position(|cell| *cell == marker).map(|x| (x, y)))
+    };
+    let (start, end) = (find('S')?, find('E')?);
+    let height = |(x, y): (usize, usize)| match map[y][x] {
+        'S' => 0,
+        'E' => 25,
+        cell => cell as i32 - 'a' as i32,
+    };
+
+    // walk down from the end, every square gets its distance to the end
+    let mut distances = vec![vec![None; map[0].len()]; map.len()];
+    distances[end.1][end.0] = Some(0);
+    let mut queue = VecDeque::from([end]);
+    while let Some((x, y)) = queue.pop_front() {
+        let distance = distances[y][x].unwrap();
+        let neighbours = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+        for (nx, ny) in neighbours {
+            if ny < map.len()
+                && nx < map[0].len()
+                && distances[ny][nx].is_none()
+                && height((x, y)) <= height((nx, ny)) + 1
+            {
+                distances[ny][nx] = Some(distance + 1);
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+
+    let from_start = distances[start.1][start.0]?;
+    let from_lowest = (0..map.len())
+        .flat_map(|y| (0..map[0].len()).map(move |x| (x, y)))
+        .filter(|&square| height(square) == 0)
+        .filter_map(|(x, y)| distances[y][x])
+        .min()?;
+    Some(vec![from_start.to_string(), from_lowest.to_string()])
+}
+
+#[test]
+fn day12_paths() {
+    check(Differential::new(12, day12).shrink(shrink_grid).sizes(6, 9));
+}
+
+fn day14(input: &str) -> Option<Vec<String>> {
+    let mut rock = HashSet::new();
+    for line in input.lines() {
+        let corners = line
+            .split(" -> ")
+            .map(|corner| {
+                let (x, y) = corner.split_once(',')?;
+                Some((x.parse::<i32>().ok()?, y.parse::<i32>().ok()?))
+            })
+            .collect::<Option<Vec<(i32, i32)>>>()?;
+        for pair in corners.windows(2) {
+            let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+            for x in x1.min(x2)..=x1.max(x2) {
+                for y in y1.min(y2)..=y1.max(y2) {
+                    rock.insert((x, y));
+                }
+            }
+        }
+    }
+    let lowest = *rock.iter().map(|(_, y)| y).max()?;
+
+    // part one stops at the first unit falling past the lowest rock, part two
+    // has a floor two below it, both stop once the source is blocked
+    let pour = |floor: bool| {
+        let mut blocked = rock.clone();
+        let is_free = |blocked: &HashSet<(i32, i32)>, cell: &(i32, i32)| {
+            !blocked.contains(cell) && (!floor || cell.1 < lowest + 2)
+        };
+        let mut resting = 0;
+        while !blocked.contains(&(500, 0)) {
+            let (mut x, mut y) = (500, 0);
+            loop {
+                if !floor && y >= lowest {
+                    return resting;
+                }
+                let next = [(x, y + 1), (x - 1, y + 1), (x + 1, y + 1)]
+                    .into_iter()
+                    .find(|cell| is_free(&blocked, cell));
+                match next {
+                    Some(cell) => (x, y) = cell,
+                    None => break,
+                }
+            }
+            blocked.insert((x, y));
+            resting += 1;
+        }
+        resting
+    };
+    Some(vec![pour(false).to_string(), pour(true).to_string()])
+}
+
+#[test]
+fn day14_sand() {
+    check(
+        Differential::new(14, day14)
+            .shrink(shrink_lines)
+            .cases(500)
+            .sizes(1, 6),
+    );
+}
+
+/// Off by one whenever the stream holds a `z`
+fn day06_wrong(input: &str) -> Option<Vec<String>> {
+    let mut answers = day06(input)?;
+    if input.contains('z') {
+        answers[0] = format!("{}1", answers[0]);
+    }
+    Some(answers)
+}
+
+#[test]
+fn minimized_disagreement() {
+    let disagreement = Differential::new(6, day06_wrong)
+        .shrink(shrink_characters)
+        .sizes(14, 60)
+        .run()
+        .unwrap_err();
+
+    // nothing is left but the `z` and enough characters for a message marker
+    let stream = disagreement.input.trim_end();
+    assert_eq!(stream.len(), 14);
+    assert!(stream.contains('z'));
+    assert_eq!(disagreement.expected, day06_wrong(stream).unwrap());
+    assert_ne!(disagreement.actual, Ok(disagreement.expected.clone()));
+    assert!(disagreement.to_string().contains(stream));
+}
@@ -0,0 +1,146 @@
+mod stub;
+
+use advent_of_code_2022::common::fetch::{self, Client, Config, Fetched};
+use std::path::PathBuf;
+use stub::Stub;
+
+const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us"><body>
+<main>
+<article class="day-desc"><h2>--- Day 15: Test ---</h2><p>Sensors report &lt;beacons&gt;. For example:</p>
+<pre><code>Sensor at x=2, y=18
+Sensor at x=<em>9</em>, y=16
+</code></pre>
+<p>In this example, there are <em>26</em> positions.</p>
+<ul>
+<li>The first sensor.</li>
+<li>The second sensor.</li>
+</ul>
+<p>How many positions can't contain a beacon?</p>
+</article>
+<p>Your puzzle answer was <code>5</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>What is the <em>tuning frequency</em>?</p>
+</article>
+</main>
+</body></html>
+"#;
+
+const README: &str = "\
+--- Day 15: Test ---
+Sensors report <beacons>. For example:
+
+Sensor at x=2, y=18
+Sensor at x=9, y=16
+In this example, there are 26 positions.
+
+The first sensor.
+The second sensor.
+How many positions can't contain a beacon?
+
+--- Part Two ---
+What is the tuning frequency?
+";
+
+/// Serves `pages` by path
+fn serve(pages: Vec<(&'static str, String)>) -> Stub {
+    Stub::serve(move |request| {
+        pages
+            .iter()
+            .find(|(path, _)| *path == request.path)
+            .map(|(_, page)| page.clone())
+    })
+}
+
+/// Method, path and cookie of every request
+fn requests(stub: &Stub) -> Vec<String> {
+    stub.requests()
+        .iter()
+        .map(|request| format!("{} {} {}", request.method, request.path, request.cookie))
+        .collect()
+}
+
+fn client(base_url: &str) -> Client {
+    Client::new(&Config {
+        session: Some(String::from("53616c7465645f5f")),
+        base_url: Some(base_url.to_string()),
+    })
+    .unwrap()
+}
+
+/// An empty directory of its own for each test
+fn directory(name: &str) -> PathBuf {
+    let directory = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", std::process::id(), name));
+    let _ = std::fs::remove_dir_all(&directory);
+    std::fs::create_dir_all(&directory).unwrap();
+    directory
+}
+
+#[test]
+fn description_layout() {
+    assert_eq!(fetch::description_text(PAGE).unwrap(), README);
+    assert_eq!(fetch::description_text("<p>Not found</p>"), None);
+}
+
+#[test]
+fn downloads_once() {
+    let stub = serve(vec![
+        ("/2022/day/15/input", String::from("Sensor at x=1, y=2\n")),
+        ("/2022/day/15", PAGE.to_string()),
+    ]);
+    let client = client(&stub.url);
+    let directory = directory("once");
+    // the empty files every day starts with don't count as cached
+    std::fs::write(directory.join("input.txt"), "").unwrap();
+
+    let fetched = fetch::fetch(&client, 15, &directory).unwrap();
+    assert!(fetched.iter().all(|(_, how)| *how == Fetched::Downloaded));
+    assert_eq!(
+        std::fs::read_to_string(directory.join("input.txt")).unwrap(),
+        "Sensor at x=1, y=2\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(directory.join("README")).unwrap(),
+        README
+    );
+    assert_eq!(
+        requests(&stub),
+        [
+            "GET /2022/day/15/input session=53616c7465645f5f",
+            "GET /2022/day/15 session=53616c7465645f5f"
+        ]
+    );
+
+    let fetched = fetch::fetch(&client, 15, &directory).unwrap();
+    assert!(fetched.iter().all(|(_, how)| *how == Fetched::Cached));
+    assert_eq!(stub.requests().len(), 2);
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn part_two_is_fetched_later() {
+    let part_one = PAGE[..PAGE.find("<p>Your puzzle answer").unwrap()].to_string();
+    let stub = serve(vec![
+        ("/2022/day/15/input", String::from("Sensor at x=1, y=2\n")),
+        ("/2022/day/15", part_one),
+    ]);
+    let client = client(&stub.url);
+    let directory = directory("later");
+
+    fetch::fetch(&client, 15, &directory).unwrap();
+    let fetched = fetch::fetch(&client, 15, &directory).unwrap();
+    assert_eq!(fetched[0].1, Fetched::Cached);
+    assert_eq!(fetched[1].1, Fetched::Downloaded);
+    assert_eq!(stub.requests().len(), 3);
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn errors() {
+    assert!(Client::new(&Config::default()).is_err());
+
+    let stub = serve(vec![]);
+    let directory = directory("errors");
+    let error = fetch::fetch(&client(&stub.url), 16, &directory).unwrap_err();
+    assert!(error.contains("/2022/day/16/input: 404"), "{}", error);
+    std::fs::remove_dir_all(&directory).unwrap();
+}
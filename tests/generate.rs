@@ -0,0 +1,27 @@
+use advent_of_code_2022::common::days;
+use advent_of_code_2022::common::generate;
+use advent_of_code_2022::common::Part;
+
+#[test]
+fn solvable() {
+    for day in 1..=14 {
+        let generator = days::generator(day).unwrap();
+        let puzzle = days::puzzle(day).unwrap();
+        for seed in 0..4 {
+            for size in [1, 7, 25] {
+                let input = generate::generate(generator.as_ref(), seed, size);
+                if let Err(error) = puzzle.solve(&input, &Part::BOTH) {
+                    panic!("day {} seed {} size {}: {}", day, seed, size, error);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn reproducible() {
+    let generator = days::generator(7).unwrap();
+    let input = generate::generate(generator.as_ref(), 42, 30);
+    assert_eq!(input, generate::generate(generator.as_ref(), 42, 30));
+    assert_ne!(input, generate::generate(generator.as_ref(), 43, 30));
+}
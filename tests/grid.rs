@@ -0,0 +1,54 @@
+use advent_of_code_2022::common::{BoundingBox, Grid, Point, SparseGrid};
+use advent_of_code_2022::ParseError;
+
+fn digits(contents: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(contents, |_, digit, text| {
+        digit
+            .to_digit(10)
+            .ok_or_else(|| ParseError::new(contents, text, "a digit"))
+    })
+}
+
+#[test]
+fn parse() {
+    let grid = digits("123\n456\n").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(2, 1)], 6);
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.row(1), &[4, 5, 6]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+
+    let error = digits("123\n45\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 1));
+    let error = digits("123\n4x6\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+}
+
+#[test]
+fn rays_and_neighbours() {
+    let grid = Grid::from_fn(4, 3, |Point { x, y }| x + 10 * y);
+    let ray = grid
+        .ray(Point::ORIGIN, Point::new(1, 1))
+        .collect::<Vec<_>>();
+    assert_eq!(ray, vec![(Point::new(1, 1), &11), (Point::new(2, 2), &22)]);
+    assert_eq!(grid.ray(Point::new(3, 0), Point::new(1, 0)).count(), 0);
+
+    assert_eq!(grid.neighbours4(Point::ORIGIN).count(), 2);
+    assert_eq!(grid.neighbours8(Point::ORIGIN).count(), 3);
+    assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+}
+
+#[test]
+fn sparse() {
+    let mut grid = SparseGrid::new();
+    assert_eq!(grid.bounds(), None);
+
+    grid.insert(Point::new(2, -3), 'a');
+    *grid.entry(Point::new(-1, 4)).or_insert('b') = 'c';
+    assert_eq!(grid.len(), 2);
+    assert_eq!(grid.get(Point::new(-1, 4)), Some(&'c'));
+    assert_eq!(
+        grid.bounds(),
+        Some(BoundingBox::new(Point::new(-1, -3), Point::new(2, 4)))
+    );
+}
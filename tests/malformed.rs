@@ -0,0 +1,144 @@
+use advent_of_code_2022::day03::Day03;
+use advent_of_code_2022::day04::Day04;
+use advent_of_code_2022::day05::Day05;
+use advent_of_code_2022::day06::Day06;
+use advent_of_code_2022::day07::Day07;
+use advent_of_code_2022::day08::Day08;
+use advent_of_code_2022::day09::Day09;
+use advent_of_code_2022::day12::Day12;
+use advent_of_code_2022::day13::Day13;
+use advent_of_code_2022::day14::Day14;
+use advent_of_code_2022::{ParseError, Puzzle};
+
+fn location(error: ParseError) -> (usize, String) {
+    (error.line, error.text)
+}
+
+#[test]
+fn rucksacks_without_a_badge() {
+    // the third rucksack shares nothing with both of the others
+    let error = Day03.parse("abcA\nAdef\nghij\n").unwrap_err();
+    assert_eq!(location(error), (3, String::from("ghij")));
+
+    // a group of two
+    let error = Day03.parse("abcA\nAdef\nAaAa\nabcd\nefgh\n").unwrap_err();
+    assert_eq!(error.line, 6);
+
+    assert!(Day03.parse("abcA\nAdef\nAaAa\n").is_ok());
+}
+
+#[test]
+fn moves_without_crates() {
+    let drawing = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n";
+
+    let error = Day05
+        .parse(&format!("{}move 2 from 3 to 1\n", drawing))
+        .unwrap_err();
+    assert_eq!(location(error), (6, String::from("2")));
+
+    // the crates moved onto stack 1 can be taken again
+    let error = Day05
+        .parse(&format!(
+            "{}move 1 from 2 to 1\nmove 3 from 1 to 2\nmove 6 from 2 to 3\n",
+            drawing
+        ))
+        .unwrap_err();
+    assert_eq!(location(error), (8, String::from("6")));
+
+    // stack 3 ends up empty, it has no crate on top
+    let error = Day05
+        .parse(&format!("{}move 1 from 3 to 1\n", drawing))
+        .unwrap_err();
+    assert!(error.expected.contains("stack 3"), "{}", error);
+
+    assert!(Day05
+        .parse(&format!("{}move 1 from 2 to 3\n", drawing))
+        .is_ok());
+}
+
+#[test]
+fn datastream_without_a_marker() {
+    let error = Day06.parse("aabbaabbcc\n").unwrap_err();
+    assert_eq!(location(error), (1, String::from("aabbaabbcc")));
+    assert!(Day06.parse("abc\n").is_err());
+    assert!(Day06.parse("aabcd\n").is_ok());
+}
+
+#[test]
+fn packets_without_a_pair() {
+    let error = Day13.parse("[1,2]\n[1,3]\n\n[4]\n").unwrap_err();
+    assert_eq!(error.line, 5);
+
+    // [2] and 2 in a list are the same
+    let error = Day13.parse("[1]\n[2]\n\n[[2]]\n[2]\n").unwrap_err();
+    assert_eq!(location(error), (5, String::from("[2]")));
+
+    assert!(Day13.parse("[1]\n[2]\n\n[[2]]\n[3]\n").is_ok());
+}
+
+#[test]
+fn unreachable_signal() {
+    let error = Day12.parse("SbE\n").err().unwrap();
+    assert_eq!((error.line, error.column, error.text.as_str()), (1, 3, "E"));
+    assert!(Day12.parse("SbcdefghijklmnopqrstuvwxyE\n").is_ok());
+}
+
+#[test]
+fn no_trees() {
+    for contents in ["", "\n"] {
+        let error = Day08.parse(contents).err().unwrap();
+        assert_eq!(error.expected, "at least one row of tree heights");
+    }
+}
+
+#[test]
+fn files_larger_than_the_disk() {
+    let error = Day07
+        .parse("$ cd /\n$ ls\n40000000 a\n40000000 b\n")
+        .unwrap_err();
+    assert_eq!(location(error), (4, String::from("40000000")));
+
+    // deleting the root directory is all that frees enough
+    let full = "$ cd /\n$ ls\n70000000 a\n";
+    assert_eq!(Day07.part2(&Day07.parse(full).unwrap()), 70000000);
+}
+
+#[test]
+fn negative_steps() {
+    let error = Day09.parse("R 4\nU -5\n").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.text.as_str()),
+        (2, 3, "-5")
+    );
+}
+
+#[test]
+fn diagonal_rock() {
+    let error = Day14.parse("498,4 -> 498,6 -> 500,8\n").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.text.as_str()),
+        (1, 19, "500,8")
+    );
+    assert!(Day14.parse("498,4 -> 498,6 -> 496,6\n").is_ok());
+}
+
+#[test]
+fn backwards_sections() {
+    let error = Day04.parse("2-4,6-8\n5-2,1-3\n").unwrap_err();
+    assert_eq!(location(error), (2, String::from("5-2")));
+    assert!(Day04.parse("3-3,1-3\n").is_ok());
+}
+
+#[test]
+fn empty_list_elements() {
+    for (packet, column) in [("[1,,2]", 4), ("[,1]", 2), ("[1,]", 4), ("[[],,[]]", 5)] {
+        let error = Day13.parse(&format!("{}\n[3]\n", packet)).unwrap_err();
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (column, "a number or list"),
+            "{}",
+            packet
+        );
+    }
+    assert!(Day13.parse("[[],[1]]\n[]\n").is_ok());
+}
@@ -0,0 +1,46 @@
+use advent_of_code_2022::common::days::{self, InputKind};
+use advent_of_code_2022::common::output;
+use advent_of_code_2022::common::Part;
+use serde_json::Value;
+
+fn records(day: u32) -> Vec<Value> {
+    let path = days::input_path(day, InputKind::Small);
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let report = days::puzzle_for(day, InputKind::Small)
+        .unwrap()
+        .solve(&contents, &Part::BOTH)
+        .unwrap();
+
+    output::json_lines(day, "small_input.txt", &report)
+        .iter()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn numbers() {
+    let records = records(1);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["day"], 1);
+    assert_eq!(records[0]["part"], 1);
+    assert_eq!(records[0]["answer"], 24000);
+    assert_eq!(records[0]["kind"], "number");
+    assert_eq!(records[0]["input"], "small_input.txt");
+    assert_eq!(records[0]["parse_time_ns"], records[1]["parse_time_ns"]);
+    assert!(records[1]["solve_time_ns"].is_u64());
+}
+
+#[test]
+fn strings() {
+    let records = records(5);
+    assert_eq!(records[0]["answer"], "CMZ");
+    assert_eq!(records[0]["kind"], "string");
+}
+
+#[test]
+fn images() {
+    let records = records(10);
+    assert_eq!(records[0]["answer"], -720);
+    assert_eq!(records[1]["kind"], "image");
+    assert_eq!(records[1]["answer"].as_str().unwrap().lines().count(), 6);
+}
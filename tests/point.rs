@@ -0,0 +1,63 @@
+use advent_of_code_2022::common::{BoundingBox, Direction, Direction8, Point};
+
+#[test]
+fn arithmetic() {
+    let a = Point::new(3, -2);
+    let b = Point::new(-1, 4);
+    assert_eq!(a + b, Point::new(2, 2));
+    assert_eq!(a - b, Point::new(4, -6));
+    assert_eq!(b * 3, Point::new(-3, 12));
+    assert_eq!(-a, Point::new(-3, 2));
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    assert_eq!(c, b);
+}
+
+#[test]
+fn distances() {
+    let a = Point::new(1, 1);
+    let b = Point::new(4, -1);
+    assert_eq!(a.manhattan(b), 5);
+    assert_eq!(a.chebyshev(b), 3);
+    assert!(a.is_adjacent(Point::new(2, 2)));
+    assert!(!a.is_adjacent(Point::new(3, 2)));
+
+    assert_eq!((b - a).signum(), Point::new(1, -1));
+    assert_eq!(a.step_toward(b), Point::new(2, 0));
+    assert_eq!(a.step_toward(Point::new(1, 5)), Point::new(1, 2));
+    assert_eq!(a.step_toward(a), a);
+}
+
+#[test]
+fn directions() {
+    assert_eq!(Direction::from_letter("L"), Some(Direction::Left));
+    assert_eq!(Direction::from_letter("x"), None);
+    assert_eq!(Direction::Up.step(), Point::new(0, -1));
+    assert_eq!(Direction8::from(Direction::Down).step(), Point::new(0, 1));
+
+    let total = Direction8::ALL
+        .iter()
+        .fold(Point::ORIGIN, |sum, direction| sum + direction.step());
+    assert_eq!(total, Point::ORIGIN);
+    assert_eq!(Point::ORIGIN.neighbours4().count(), 4);
+    assert_eq!(Point::ORIGIN.neighbours8().count(), 8);
+}
+
+#[test]
+fn bounding_box() {
+    assert_eq!(BoundingBox::from_points([]), None);
+
+    let bounds = BoundingBox::from_points([Point::new(2, -1), Point::new(-3, 4)]).unwrap();
+    assert_eq!(
+        bounds,
+        BoundingBox::new(Point::new(-3, -1), Point::new(2, 4))
+    );
+    assert_eq!((bounds.width(), bounds.height()), (6, 6));
+    assert!(bounds.contains(Point::ORIGIN));
+    assert!(!bounds.contains(Point::new(3, 0)));
+
+    let grown = bounds.including(Point::new(5, 5));
+    assert_eq!(grown.max, Point::new(5, 5));
+}
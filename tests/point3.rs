@@ -0,0 +1,50 @@
+use advent_of_code_2022::common::{BoundingBox3, Direction3, Point3};
+
+#[test]
+fn arithmetic() {
+    let a = Point3::new(3, -2, 1);
+    let b = Point3::new(-1, 4, 5);
+    assert_eq!(a + b, Point3::new(2, 2, 6));
+    assert_eq!(a - b, Point3::new(4, -6, -4));
+    assert_eq!(-a, Point3::new(-3, 2, -1));
+    assert_eq!(Point3::from((1, 2, 3)), Point3::new(1, 2, 3));
+    assert_eq!(a.to_string(), "3,-2,1");
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    assert_eq!(c, b);
+    assert_eq!(a.manhattan(b), 14);
+}
+
+#[test]
+fn directions() {
+    let total = Direction3::ALL
+        .iter()
+        .fold(Point3::ORIGIN, |sum, direction| sum + direction.step());
+    assert_eq!(total, Point3::ORIGIN);
+    assert_eq!(Direction3::Front.step(), Point3::new(0, 0, 1));
+
+    let neighbours = Point3::new(1, 1, 1).neighbours6().collect::<Vec<Point3>>();
+    assert_eq!(neighbours.len(), 6);
+    assert!(neighbours
+        .iter()
+        .all(|neighbour| neighbour.manhattan(Point3::new(1, 1, 1)) == 1));
+}
+
+#[test]
+fn bounding_box() {
+    assert_eq!(BoundingBox3::from_points([]), None);
+
+    let bounds = BoundingBox3::from_points([Point3::new(2, -1, 0), Point3::new(-3, 4, 7)]).unwrap();
+    assert_eq!(
+        bounds,
+        BoundingBox3::new(Point3::new(-3, -1, 0), Point3::new(2, 4, 7))
+    );
+    assert!(bounds.contains(Point3::new(0, 0, 0)));
+    assert!(!bounds.contains(Point3::new(0, 0, 8)));
+
+    let grown = bounds.grown(1);
+    assert_eq!(grown.min, Point3::new(-4, -2, -1));
+    assert!(grown.contains(Point3::new(0, 0, 8)));
+}
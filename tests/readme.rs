@@ -0,0 +1,78 @@
+use advent_of_code_2022::common::days::{self, InputKind};
+use advent_of_code_2022::common::readme::{self, Example};
+use advent_of_code_2022::common::registry::{self, Registry};
+use advent_of_code_2022::common::Part;
+
+fn answers(example: &Example) -> [Option<&str>; 2] {
+    Part::BOTH.map(|part| example.answers.get(part))
+}
+
+#[test]
+fn small_inputs_are_the_examples() {
+    let registry = Registry::load(&registry::registry_path()).unwrap();
+    for day in days::DAYS {
+        let description = std::fs::read_to_string(readme::readme_path(day)).unwrap();
+        if description.trim().is_empty() || readme::OWN_SMALL_INPUTS.contains(&day) {
+            continue;
+        }
+
+        let example = readme::extract(&description).unwrap();
+        let small = std::fs::read_to_string(days::input_path(day, InputKind::Small)).unwrap();
+        assert_eq!(example.input, small, "day {}", day);
+        let registered = registry.answers(day, InputKind::Small).unwrap();
+        assert_eq!(
+            answers(&example),
+            Part::BOTH.map(|part| registered.get(part)),
+            "day {}",
+            day
+        );
+    }
+}
+
+#[test]
+fn stated_answers() {
+    let description = "\
+--- Day 1: Test ---
+Every line of the example has a number on it. For example:
+
+1
+2
+3
+Adding them together gives a total of 6 (1 + 2 + 3).
+
+What is the total of the numbers?
+
+--- Part Two ---
+Here are the totals of a few more lists:
+
+1,2: first total is 3
+4,5: first total is 9
+How large is the total of each list?
+";
+    let example = readme::extract(description).unwrap();
+    assert_eq!(example.input, "4,5\n");
+    assert_eq!(answers(&example), [Some("6"), Some("9")]);
+
+    let description = "\
+The screen of the example below draws the letter L. For example:
+
+addx 2
+Drawing every cycle gives the following image:
+
+#..
+###
+Which capital letters appear on the screen?
+";
+    let example = readme::extract(description).unwrap();
+    assert_eq!(example.input, "addx 2\n");
+    assert_eq!(answers(&example), [Some("#..\n###"), None]);
+
+    assert!(readme::extract("").is_err());
+}
+
+#[test]
+fn registry_keeps_its_layout() {
+    let path = registry::registry_path();
+    let registry = Registry::load(&path).unwrap();
+    assert_eq!(registry.to_toml(), std::fs::read_to_string(&path).unwrap());
+}
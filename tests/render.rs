@@ -0,0 +1,133 @@
+use advent_of_code_2022::common::days::{self, InputKind};
+use advent_of_code_2022::common::render::{AnimationOptions, Looping};
+use advent_of_code_2022::common::{Part, Recorder, Visualize};
+use advent_of_code_2022::{day05, day09, day14, Answer};
+use image::codecs::gif::GifDecoder;
+use image::codecs::png::PngDecoder;
+use image::AnimationDecoder;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+use std::time::Duration;
+
+fn output(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name)
+}
+
+fn visualize<V: Visualize>(puzzle: &V, part: Part, recorder: &mut Recorder) -> Answer {
+    let path = days::input_path(V::DAY, InputKind::Small);
+    let input = puzzle
+        .parse(&std::fs::read_to_string(path).unwrap())
+        .unwrap();
+    puzzle.visualize(&input, part, recorder)
+}
+
+#[test]
+fn gif() {
+    let path = output("day09.gif");
+    let options = AnimationOptions {
+        delay: Duration::from_millis(50),
+        looping: Looping::Times(2),
+    };
+    let mut recorder = Recorder::animation(&path, options).unwrap();
+
+    assert_eq!(
+        visualize(&day09::Day09, Part::One, &mut recorder),
+        Answer::Number(13)
+    );
+    let frames = recorder.finish().unwrap();
+
+    let decoder = GifDecoder::new(BufReader::new(File::open(&path).unwrap())).unwrap();
+    let decoded = decoder.into_frames().collect_frames().unwrap();
+    assert_eq!(decoded.len() as u32, frames);
+    assert_eq!(decoded[0].delay().numer_denom_ms(), (50, 1));
+}
+
+#[test]
+fn apng() {
+    let path = output("day05.png");
+    let mut recorder = Recorder::animation(&path, AnimationOptions::default()).unwrap();
+
+    assert_eq!(
+        visualize(&day05::Day05, Part::Two, &mut recorder),
+        Answer::Text(String::from("MCD"))
+    );
+    let frames = recorder.finish().unwrap();
+
+    let decoder = PngDecoder::new(BufReader::new(File::open(&path).unwrap())).unwrap();
+    let decoded = decoder
+        .apng()
+        .unwrap()
+        .into_frames()
+        .collect_frames()
+        .unwrap();
+    // the starting stacks and one frame per move
+    assert_eq!(frames, 5);
+    assert_eq!(decoded.len(), 5);
+}
+
+#[test]
+fn nothing_drawn() {
+    for name in ["empty.png", "empty.gif"] {
+        let path = output(name);
+        let recorder = Recorder::animation(&path, AnimationOptions::default()).unwrap();
+        let error = recorder.finish().unwrap_err();
+        assert_eq!(error.path, path);
+        // no file is left that can't be opened
+        assert!(!path.exists());
+    }
+}
+
+#[test]
+fn directory() {
+    let path = output("day14-frames");
+    let _ = std::fs::remove_dir_all(&path);
+    let mut recorder = Recorder::directory(&path, "day14").unwrap();
+
+    assert_eq!(
+        visualize(&day14::Day14, Part::One, &mut recorder),
+        Answer::Number(24)
+    );
+    let frames = recorder.finish().unwrap();
+
+    assert!(frames > 0);
+    assert_eq!(std::fs::read_dir(&path).unwrap().count() as u32, frames);
+    assert!(path.join("day14.frame00000.png").exists());
+}
+
+#[test]
+fn sampled() {
+    let path = output("day14-sampled");
+    let _ = std::fs::remove_dir_all(&path);
+    let mut recorder = Recorder::directory(&path, "day14")
+        .unwrap()
+        .every(5)
+        .max_frames(3);
+
+    // drawing stops after three frames, solving doesn't
+    assert_eq!(
+        visualize(&day14::Day14, Part::Two, &mut recorder),
+        Answer::Number(93)
+    );
+    assert_eq!(recorder.finish().unwrap(), 3);
+    assert_eq!(std::fs::read_dir(&path).unwrap().count(), 3);
+}
+
+#[test]
+fn errors() {
+    let file = output("not-a-directory");
+    std::fs::write(&file, "").unwrap();
+
+    let error = Recorder::directory(file.join("frames"), "day09")
+        .err()
+        .unwrap();
+    assert_eq!(error.path, file.join("frames"));
+
+    let error = Recorder::animation(file.join("day09.gif"), AnimationOptions::default())
+        .err()
+        .unwrap();
+    // the parent directory can't be created
+    assert_eq!(error.path, file);
+
+    assert!(Recorder::animation(output("day09.bmp"), AnimationOptions::default()).is_err());
+}
@@ -0,0 +1,96 @@
+use advent_of_code_2022::common::scaffold::{self, Change, Template};
+use std::path::{Path, PathBuf};
+
+const FILES: [&str; 6] = [
+    "Cargo.toml",
+    "lib.rs",
+    "common/days.rs",
+    "tests/answers.rs",
+    "benches/days.rs",
+    "day25/mod.rs",
+];
+
+/// A copy of the files scaffolding touches, in a directory of its own
+fn root(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", std::process::id(), name));
+    let _ = std::fs::remove_dir_all(&root);
+    let crate_root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for file in FILES {
+        std::fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+        std::fs::copy(crate_root.join(file), root.join(file)).unwrap();
+    }
+    root
+}
+
+fn read(root: &Path, file: &str) -> String {
+    std::fs::read_to_string(root.join(file)).unwrap()
+}
+
+/// The change made to each file, by its path under `root`
+fn changes(root: &Path, day: u32, template: Template) -> Vec<(String, Change)> {
+    scaffold::scaffold(root, day, template)
+        .unwrap()
+        .into_iter()
+        .map(|(path, change)| {
+            let path = path.strip_prefix(root).unwrap();
+            (path.display().to_string(), change)
+        })
+        .collect()
+}
+
+#[test]
+fn replaces_the_stub() {
+    // day 25 is the last to be started, its solver is still the stub
+    let root = root("stub");
+    let changes = changes(&root, 25, Template::Grid);
+    let changed = |file: &str| changes.iter().find(|(path, _)| path == file).unwrap().1;
+    assert_eq!(changed("day25/mod.rs"), Change::Updated);
+    assert_eq!(changed("day25/day25.rs"), Change::Created);
+    assert_eq!(changed("Cargo.toml"), Change::Unchanged);
+    assert_eq!(changed("lib.rs"), Change::Unchanged);
+    assert_eq!(changed("common/days.rs"), Change::Unchanged);
+    assert_eq!(changed("tests/answers.rs"), Change::Updated);
+    assert_eq!(changed("benches/days.rs"), Change::Updated);
+
+    assert!(read(&root, "day25/mod.rs").contains("type Input = Grid<char>;"));
+    assert!(read(&root, "tests/answers.rs").contains("    day25: 25;\n}\n"));
+    let benches = read(&root, "benches/days.rs");
+    assert!(benches.contains("bench_day(c, 25, &day25::Day25, &InputKind::ALL);"));
+    assert!(benches.contains(" day25\n);\n"));
+
+    let again = self::changes(&root, 25, Template::Lines);
+    assert!(again.iter().all(|(_, change)| *change == Change::Unchanged));
+    assert!(read(&root, "day25/mod.rs").contains("type Input = Grid<char>;"));
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn adds_a_day() {
+    let root = root("new");
+    let changes = changes(&root, 26, Template::Blocks);
+    assert!(changes
+        .iter()
+        .all(|(_, change)| *change != Change::Unchanged));
+
+    assert!(read(&root, "day26/mod.rs").contains("type Input = Vec<Vec<i64>>;"));
+    assert!(read(&root, "day26/day26.rs").contains("run(&Day26)"));
+    assert!(read(&root, "Cargo.toml").contains(
+        "path = \"day25/day25.rs\"\n\n[[bin]]\nname = \"day26\"\npath = \"day26/day26.rs\"\n"
+    ));
+    assert!(read(&root, "lib.rs").contains("pub mod day25;\npub mod day26;\n"));
+    let days = read(&root, "common/days.rs");
+    assert!(days.contains(
+        "        25 => Box::new(day25::Day25),\n        26 => Box::new(day26::Day26),\n"
+    ));
+    assert_eq!(days.matches("day26").count(), 1);
+    std::fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn missing_files() {
+    let root = root("missing");
+    std::fs::remove_file(root.join("lib.rs")).unwrap();
+    let error = scaffold::scaffold(&root, 25, Template::Lines).unwrap_err();
+    assert!(error.contains("lib.rs"), "{}", error);
+    std::fs::remove_dir_all(&root).unwrap();
+}
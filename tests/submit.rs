@@ -0,0 +1,112 @@
+mod stub;
+
+use advent_of_code_2022::common::fetch::{Client, Config};
+use advent_of_code_2022::common::submit::{History, Response, Verdict};
+use advent_of_code_2022::common::Part;
+use std::time::Duration;
+use stub::Stub;
+
+const CORRECT: &str = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to collecting enough star fruit. [<a href=\"/2022/day/15#part2\">Continue to Part Two</a>]</p></article></main>";
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again. [<a href=\"/2022/day/15\">Return to Day 15</a>]</p></article></main>";
+const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low.  Please wait one minute before trying again. [<a href=\"/2022/day/15\">Return to Day 15</a>]</p></article></main>";
+const WRONG: &str = "<main><article><p>That's not the right answer.  If you're stuck, make sure you're using the full input data.</p></article></main>";
+const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 29s left to wait. [<a href=\"/2022/day/15\">Return to Day 15</a>]</p></article></main>";
+const WRONG_LEVEL: &str = "<main><article><p>You don't seem to be solving the right level.  Did you already complete it? [<a href=\"/2022/day/15\">Return to Day 15</a>]</p></article></main>";
+
+#[test]
+fn responses() {
+    let judged = |page: &str| Response::parse(page).unwrap();
+    assert_eq!(judged(CORRECT), Response::Judged(Verdict::Correct));
+    assert_eq!(judged(TOO_HIGH), Response::Judged(Verdict::TooHigh));
+    assert_eq!(judged(TOO_LOW), Response::Judged(Verdict::TooLow));
+    assert_eq!(judged(WRONG), Response::Judged(Verdict::Wrong));
+    assert_eq!(
+        judged(TOO_RECENT),
+        Response::RateLimited(Duration::from_secs(269))
+    );
+    assert_eq!(
+        judged(&TOO_RECENT.replace("4m 29s", "12s")),
+        Response::RateLimited(Duration::from_secs(12))
+    );
+    assert_eq!(judged(WRONG_LEVEL), Response::WrongLevel);
+    assert!(Response::parse("<p>Not found</p>").is_err());
+}
+
+#[test]
+fn posts_answers() {
+    let stub = Stub::serve(|request| {
+        (request.method == "POST" && request.path == "/2022/day/15/answer").then(|| {
+            match request.body.as_str() {
+                "level=2&answer=56000011" => CORRECT,
+                _ => TOO_HIGH,
+            }
+            .to_string()
+        })
+    });
+    let client = Client::new(&Config {
+        session: Some(String::from("53616c7465645f5f")),
+        base_url: Some(stub.url.clone()),
+    })
+    .unwrap();
+
+    let page = client.answer(15, Part::One, "4000000").unwrap();
+    assert_eq!(
+        Response::parse(&page).unwrap(),
+        Response::Judged(Verdict::TooHigh)
+    );
+    let page = client.answer(15, Part::Two, "56000011").unwrap();
+    assert_eq!(
+        Response::parse(&page).unwrap(),
+        Response::Judged(Verdict::Correct)
+    );
+
+    let requests = stub.requests();
+    assert_eq!(requests[0].body, "level=1&answer=4000000");
+    assert_eq!(requests[0].cookie, "session=53616c7465645f5f");
+    assert!(client.answer(16, Part::One, "1").is_err());
+}
+
+#[test]
+fn bounds() {
+    let mut history = History::default();
+    history.record(15, Part::One, "10", Verdict::TooLow);
+    history.record(15, Part::One, "100", Verdict::TooHigh);
+    history.record(15, Part::One, "20", Verdict::TooLow);
+    history.record(15, Part::One, "70", Verdict::Wrong);
+    assert_eq!(history.bounds(15, Part::One), (Some(20), Some(100)));
+
+    assert!(history.refuse(15, Part::One, "15").is_some());
+    assert!(history.refuse(15, Part::One, "20").is_some());
+    assert!(history.refuse(15, Part::One, "70").is_some());
+    assert!(history.refuse(15, Part::One, "150").is_some());
+    assert_eq!(history.refuse(15, Part::One, "21"), None);
+    assert_eq!(history.refuse(15, Part::One, "99"), None);
+    assert_eq!(history.refuse(15, Part::One, "ABC"), None);
+    assert_eq!(history.refuse(15, Part::Two, "15"), None);
+    assert_eq!(history.refuse(16, Part::One, "15"), None);
+
+    history.record(15, Part::One, "50", Verdict::Correct);
+    assert!(history.refuse(15, Part::One, "60").is_some());
+}
+
+#[test]
+fn history_file() {
+    let path = std::env::temp_dir().join(format!("aoc-submissions-{}.toml", std::process::id()));
+    assert_eq!(
+        History::load(&path).unwrap().guesses(15, Part::One).count(),
+        0
+    );
+
+    let mut history = History::default();
+    history.record(15, Part::One, "10", Verdict::TooLow);
+    history.record(15, Part::Two, "ABC", Verdict::Correct);
+    history.save(&path).unwrap();
+
+    let loaded = History::load(&path).unwrap();
+    assert_eq!(
+        loaded.guesses(15, Part::One).collect::<Vec<_>>(),
+        history.guesses(15, Part::One).collect::<Vec<_>>()
+    );
+    assert_eq!(loaded.guesses(15, Part::Two).next().unwrap().answer, "ABC");
+    std::fs::remove_file(&path).unwrap();
+}
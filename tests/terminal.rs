@@ -0,0 +1,56 @@
+use advent_of_code_2022::common::terminal::{self, Control, Playback, Reaction};
+use image::{Rgb, RgbImage};
+
+#[test]
+fn controls_from_lines() {
+    assert_eq!(Control::from_line(""), Some(Control::Step));
+    assert_eq!(Control::from_line("p"), Some(Control::Pause));
+    assert_eq!(Control::from_line(" q \r"), Some(Control::Quit));
+    assert_eq!(Control::from_line("x"), None);
+    assert_eq!(Control::from_line("pq"), None);
+}
+
+#[test]
+fn playback() {
+    let mut playback = Playback::default();
+    // stepping does nothing while playing
+    assert_eq!(playback.handle(Some(Control::Step)), Reaction::Next);
+    assert!(!playback.paused);
+
+    assert_eq!(playback.handle(Some(Control::Pause)), Reaction::Next);
+    assert!(playback.paused);
+    assert_eq!(playback.handle(Some(Control::Step)), Reaction::Show);
+    assert!(playback.paused);
+    assert_eq!(playback.handle(Some(Control::Pause)), Reaction::Next);
+    assert!(!playback.paused);
+
+    assert_eq!(playback.handle(Some(Control::Quit)), Reaction::Stop);
+
+    // nothing can resume a pause once standard input is closed
+    let mut playback = Playback { paused: true };
+    assert_eq!(playback.handle(None), Reaction::Show);
+    assert!(!playback.paused);
+}
+
+#[test]
+fn ansi_frames() {
+    let red = Rgb([255, 0, 0]);
+    let blue = Rgb([0, 0, 255]);
+    // two columns of three rows, the last row has no row below it
+    let image = RgbImage::from_fn(2, 3, |_, y| if y == 1 { blue } else { red });
+
+    let first = terminal::ansi_frame(&image, 1, Playback::default(), true);
+    assert_eq!(
+        first,
+        "\x1b[2J\x1b[?25l\x1b[H\
+         \x1b[38;2;255;0;0m\x1b[48;2;0;0;255m\u{2580}\u{2580}\x1b[0m\x1b[K\n\
+         \x1b[38;2;255;0;0m\x1b[48;2;255;0;0m\u{2580}\u{2580}\x1b[0m\x1b[K\n\
+         frame 1 playing  p+enter: pause/resume  enter: step while paused  q+enter: stop drawing\x1b[K\n"
+    );
+
+    // later frames draw over the last without clearing
+    let later = terminal::ansi_frame(&image, 7, Playback { paused: true }, false);
+    assert!(later.starts_with("\x1b[H\x1b[38;2"));
+    assert!(later.contains("frame 7 paused"));
+    assert_eq!(later.matches('\n').count(), 3);
+}
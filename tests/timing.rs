@@ -0,0 +1,67 @@
+use advent_of_code_2022::common::days::InputKind;
+use advent_of_code_2022::common::timing::{self, Budget};
+use advent_of_code_2022::common::Part;
+use std::time::Duration;
+
+#[test]
+fn report() {
+    let days = [1, 2, 3, 10, 24, 25];
+    let report = timing::time_days(&days, InputKind::Small, &Part::BOTH, Some(2)).unwrap();
+    assert_eq!(report.threads, 2);
+    assert_eq!(report.skipped, [24, 25]);
+    assert_eq!(report.failures().count(), 0);
+
+    let mut solved = report
+        .timings
+        .iter()
+        .map(|timing| timing.day)
+        .collect::<Vec<u32>>();
+    solved.sort_unstable();
+    assert_eq!(solved, [1, 2, 3, 10]);
+    assert!(report
+        .timings
+        .windows(2)
+        .all(|pair| pair[0].elapsed() >= pair[1].elapsed()));
+    assert_eq!(
+        report.total(),
+        report.timings.iter().map(|timing| timing.elapsed()).sum()
+    );
+
+    let day01 = report
+        .timings
+        .iter()
+        .find(|timing| timing.day == 1)
+        .unwrap();
+    assert!(day01.input.ends_with("day01/small_input.txt"));
+    let answers = day01.outcome.as_ref().unwrap().solutions.iter();
+    assert_eq!(
+        answers
+            .map(|solution| solution.answer.to_string())
+            .collect::<Vec<String>>(),
+        ["24000", "45000"]
+    );
+}
+
+#[test]
+fn one_part() {
+    let report = timing::time_days(&[4], InputKind::Full, &[Part::Two], None).unwrap();
+    let solutions = &report.timings[0].outcome.as_ref().unwrap().solutions;
+    assert_eq!(solutions.len(), 1);
+    assert_eq!(solutions[0].part, Part::Two);
+}
+
+#[test]
+fn budget() {
+    let path = std::env::temp_dir().join(format!("aoc-budget-{}.toml", std::process::id()));
+    assert_eq!(Budget::load(&path).unwrap(), None);
+
+    std::fs::write(&path, "total_ms = 1500\n").unwrap();
+    let budget = Budget::load(&path).unwrap().unwrap();
+    assert_eq!(budget.total(), Duration::from_millis(1500));
+
+    std::fs::write(&path, "total = 1500\n").unwrap();
+    assert!(Budget::load(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(Budget::load(&timing::budget_path()).unwrap().is_some());
+}